@@ -0,0 +1,65 @@
+//! Generates a test for every example input under `examples/`, which the `aoc_test!` macro
+//! includes. See `src/examples.rs` for the layout of the examples.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {err}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+    entries
+}
+
+fn test_name(input: &Path) -> String {
+    let stem = input.file_stem().unwrap().to_string_lossy();
+    let name: String =
+        stem.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) { format!("_{name}") } else { name }
+}
+
+fn ignore_reason(input: &Path) -> Option<String> {
+    let answers = fs::read_to_string(input.with_extension("answers")).ok()?;
+    answers.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "ignore").then(|| value.trim().into())
+    })
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed=examples");
+
+    let out_dir = Path::new(&std::env::var("OUT_DIR").unwrap()).join("examples");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let examples_dir = Path::new("examples");
+    if !examples_dir.is_dir() {
+        return;
+    }
+
+    for day_dir in sorted_entries(examples_dir).into_iter().filter(|path| path.is_dir()) {
+        let mut tests = String::new();
+        for input in sorted_entries(&day_dir) {
+            if input.extension().is_none_or(|extension| extension != "txt") {
+                continue;
+            }
+
+            if let Some(reason) = ignore_reason(&input) {
+                writeln!(tests, "#[ignore = {reason:?}]").unwrap();
+            }
+            let file_name = input.file_name().unwrap().to_string_lossy();
+            writeln!(
+                tests,
+                "#[test]\nfn {}() {{\n    check({file_name:?});\n}}\n",
+                test_name(&input)
+            )
+            .unwrap();
+        }
+
+        let day = day_dir.file_name().unwrap().to_string_lossy();
+        fs::write(out_dir.join(format!("{day}.rs")), tests).unwrap();
+    }
+}
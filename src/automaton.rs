@@ -0,0 +1,298 @@
+//! 2D cellular automata over [`Grid2D`]
+//!
+//! The transition rule receives a cell's current state and the states of its neighbors, and
+//! returns the cell's next state. Neighbors outside a bounded grid are omitted, so cells on the
+//! edges see fewer neighbors.
+
+use crate::{Grid2D, Point2D};
+use rustc_hash::FxHashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::hash::Hash;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutomatonError {
+    EmptyGrid { rows: usize, cols: usize },
+}
+
+impl Display for AutomatonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyGrid { rows, cols } => write!(f, "Grid is empty ({rows}x{cols})"),
+        }
+    }
+}
+
+impl Error for AutomatonError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The 4 orthogonally adjacent cells
+    VonNeumann,
+    /// The 8 orthogonally and diagonally adjacent cells
+    Moore,
+    /// Arbitrary (dx, dy) offsets
+    Custom(Vec<Point2D<i64>>),
+}
+
+impl Neighborhood {
+    fn offsets(&self) -> Vec<Point2D<i64>> {
+        let p = |x, y| Point2D { x, y };
+        match self {
+            Self::VonNeumann => vec![p(0, -1), p(-1, 0), p(1, 0), p(0, 1)],
+            Self::Moore => (-1..=1)
+                .flat_map(|y| (-1..=1).map(move |x| p(x, y)))
+                .filter(|&o| o != p(0, 0))
+                .collect(),
+            Self::Custom(offsets) => offsets.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    Bounded,
+    Toroidal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateMode {
+    /// Every cell's next state is computed from the previous generation
+    Synchronous,
+    /// Cells are updated in place in row-major order, so later cells see the updated states of
+    /// earlier cells in the same generation
+    Asynchronous,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The grid stopped changing; this is the first generation of the final state
+    Stable { generation: usize },
+    /// The grid returned to the state it had at an earlier generation
+    Cycle { start: usize, period: usize },
+    /// The step limit was reached without the grid stabilizing or cycling
+    StepLimit,
+}
+
+pub struct Automaton<T, F> {
+    grid: Grid2D<T>,
+    offsets: Vec<Point2D<i64>>,
+    edges: Edges,
+    mode: UpdateMode,
+    rule: F,
+    generation: usize,
+}
+
+impl<T, F> Automaton<T, F>
+where
+    T: Clone + PartialEq,
+    F: Fn(&T, &[T]) -> T,
+{
+    /// Creates an automaton at generation 0. The grid must have at least one row and one column.
+    pub fn new(
+        grid: Grid2D<T>,
+        neighborhood: Neighborhood,
+        edges: Edges,
+        mode: UpdateMode,
+        rule: F,
+    ) -> Result<Self, AutomatonError> {
+        let rows = grid.rows();
+        let cols = grid.0.first().map_or(0, Vec::len);
+        if rows == 0 || cols == 0 {
+            return Err(AutomatonError::EmptyGrid { rows, cols });
+        }
+
+        Ok(Self { grid, offsets: neighborhood.offsets(), edges, mode, rule, generation: 0 })
+    }
+
+    pub fn grid(&self) -> &Grid2D<T> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> Grid2D<T> {
+        self.grid
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn neighbor(&self, point: Point2D<usize>, offset: Point2D<i64>) -> Option<Point2D<usize>> {
+        let rows = self.grid.rows() as i64;
+        let cols = self.grid.cols() as i64;
+        let x = point.x as i64 + offset.x;
+        let y = point.y as i64 + offset.y;
+
+        match self.edges {
+            Edges::Bounded => ((0..cols).contains(&x) && (0..rows).contains(&y))
+                .then_some(Point2D { x: x as usize, y: y as usize }),
+            Edges::Toroidal => {
+                Some(Point2D { x: x.rem_euclid(cols) as usize, y: y.rem_euclid(rows) as usize })
+            }
+        }
+    }
+
+    fn next_state(&self, source: &Grid2D<T>, point: Point2D<usize>, neighbors: &mut Vec<T>) -> T {
+        neighbors.clear();
+        neighbors.extend(
+            self.offsets
+                .iter()
+                .filter_map(|&offset| self.neighbor(point, offset))
+                .map(|neighbor| source[neighbor].clone()),
+        );
+
+        (self.rule)(&source[point], neighbors)
+    }
+
+    /// Advances one generation. Returns whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        let mut neighbors = Vec::with_capacity(self.offsets.len());
+        let mut changed = false;
+
+        let points: Vec<_> = (0..self.grid.rows())
+            .flat_map(|y| (0..self.grid.cols()).map(move |x| Point2D { x, y }))
+            .collect();
+
+        match self.mode {
+            UpdateMode::Synchronous => {
+                let mut next = self.grid.clone();
+                for &point in &points {
+                    let state = self.next_state(&self.grid, point, &mut neighbors);
+                    if state != self.grid[point] {
+                        next[point] = state;
+                        changed = true;
+                    }
+                }
+                self.grid = next;
+            }
+            UpdateMode::Asynchronous => {
+                for &point in &points {
+                    let state = self.next_state(&self.grid, point, &mut neighbors);
+                    if state != self.grid[point] {
+                        self.grid[point] = state;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        self.generation += 1;
+        changed
+    }
+
+    /// Steps until the grid stops changing, returns to a previously seen state, or `max_steps`
+    /// generations have run.
+    pub fn run_until_stable(&mut self, max_steps: usize) -> Outcome
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = FxHashMap::default();
+        seen.insert(self.grid.clone(), self.generation);
+
+        for _ in 0..max_steps {
+            if !self.step() {
+                return Outcome::Stable { generation: self.generation - 1 };
+            }
+
+            if let Some(&start) = seen.get(&self.grid) {
+                return Outcome::Cycle { start, period: self.generation - start };
+            }
+            seen.insert(self.grid.clone(), self.generation);
+        }
+
+        Outcome::StepLimit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Grid2D<bool> {
+        Grid2D(s.lines().map(|line| line.chars().map(|c| c == '#').collect()).collect())
+    }
+
+    fn life(alive: &bool, neighbors: &[bool]) -> bool {
+        let count = neighbors.iter().filter(|&&n| n).count();
+        matches!((alive, count), (true, 2 | 3) | (false, 3))
+    }
+
+    #[test]
+    fn life_blinker_and_glider() {
+        let blinker = parse(".....\n..#..\n..#..\n..#..\n.....");
+        let mut automaton = Automaton::new(
+            blinker,
+            Neighborhood::Moore,
+            Edges::Bounded,
+            UpdateMode::Synchronous,
+            life,
+        )
+        .unwrap();
+        assert_eq!(Outcome::Cycle { start: 0, period: 2 }, automaton.run_until_stable(10));
+
+        // A glider on a 5x5 torus returns to its starting position after 20 generations
+        let glider = parse(".#...\n..#..\n###..\n.....\n.....");
+        let mut automaton = Automaton::new(
+            glider,
+            Neighborhood::Moore,
+            Edges::Toroidal,
+            UpdateMode::Synchronous,
+            life,
+        )
+        .unwrap();
+        assert_eq!(Outcome::Cycle { start: 0, period: 20 }, automaton.run_until_stable(100));
+        assert_eq!(Outcome::StepLimit, automaton.run_until_stable(5));
+    }
+
+    #[test]
+    fn day4_removal() {
+        let grid = parse(
+            "
+..##.####.
+###.#.#.##
+#####.#.##
+#.####..#.
+##.####.##
+.#######.#
+.#.#.#.###
+#.###.####
+.########.
+#.#.###.#.
+"
+            .trim(),
+        );
+        let initial = grid.0.iter().flatten().filter(|&&b| b).count();
+
+        // Removal order doesn't affect the final state, so both modes should agree
+        for mode in [UpdateMode::Synchronous, UpdateMode::Asynchronous] {
+            let mut automaton = Automaton::new(
+                grid.clone(),
+                Neighborhood::Moore,
+                Edges::Bounded,
+                mode,
+                |&occupied: &bool, neighbors: &[bool]| {
+                    occupied && neighbors.iter().filter(|&&n| n).count() >= 4
+                },
+            )
+            .unwrap();
+            assert!(matches!(automaton.run_until_stable(100), Outcome::Stable { .. }));
+
+            let remaining = automaton.grid().0.iter().flatten().filter(|&&b| b).count();
+            assert_eq!(43, initial - remaining);
+        }
+    }
+
+    #[test]
+    fn empty_grid() {
+        for (grid, rows) in [(Grid2D(vec![]), 0), (Grid2D(vec![vec![], vec![]]), 2)] {
+            let automaton = Automaton::new(
+                grid,
+                Neighborhood::Moore,
+                Edges::Toroidal,
+                UpdateMode::Synchronous,
+                life,
+            );
+            assert_eq!(Some(AutomatonError::EmptyGrid { rows, cols: 0 }), automaton.err());
+        }
+    }
+}
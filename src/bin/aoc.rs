@@ -0,0 +1,295 @@
+//! Tooling for working on the puzzles, as opposed to solving them
+//!
+//! Usage:
+//! - `aoc gen <day> [--seed <seed>] [--size <size>]` prints a randomly generated input for the
+//!   given day. The seed defaults to 0, and the size defaults to roughly the size of the real
+//!   input; see [`advent_of_code_2025::generate`] for what the size controls for each day.
+//! - `aoc extract <day> <page.html>` extracts the examples and their answers from a saved puzzle
+//!   page into `examples/day<day>/`; see [`advent_of_code_2025::puzzle_page`] for how. Existing
+//!   example files are never overwritten.
+//! - `aoc fetch <day> [--base-url <url>]` downloads the input for the given day into
+//!   `inputs/day<day>.txt` and prints its path, or just prints the path if it's already there;
+//!   see [`advent_of_code_2025::fetch`] for where the session token comes from. Downloading
+//!   needs the `fetch` feature, e.g. `cargo run --features fetch --bin aoc -- fetch 1`.
+//! - `aoc new <day> --title <title> [--page <page.html>]` creates the files for a new day and adds
+//!   it to `src/lib.rs`, with the example extracted from the puzzle page if one is given; see
+//!   [`advent_of_code_2025::scaffold`]. It refuses to touch a day that already exists.
+//!
+//! Paths are relative to the working directory, which should be the repository root.
+
+use advent_of_code_2025::{fetch, generate, puzzle_page, scaffold};
+use rustc_hash::FxHashMap;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const USAGE: &str = concat!(
+    "Usage: aoc gen <day> [--seed <seed>] [--size <size>]",
+    " | aoc extract <day> <page.html>",
+    " | aoc fetch <day> [--base-url <url>]",
+    " | aoc new <day> --title <title> [--page <page.html>]"
+);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Args {
+    positional: Vec<String>,
+    options: FxHashMap<String, String>,
+}
+
+impl Args {
+    // Options are `--name value` pairs; everything else is positional
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = args.next().ok_or_else(|| format!("Missing value for --{name}"))?;
+                    parsed.options.insert(name.into(), value);
+                }
+                None => parsed.positional.push(arg),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    fn check_options(&self, allowed: &[&str]) -> Result<(), String> {
+        match self.options.keys().find(|name| !allowed.contains(&name.as_str())) {
+            Some(name) => Err(format!("Unknown option --{name}\n{USAGE}")),
+            None => Ok(()),
+        }
+    }
+
+    fn option<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        self.options
+            .get(name)
+            .map(|value| value.parse().map_err(|_| format!("Invalid value '{value}' for --{name}")))
+            .transpose()
+    }
+}
+
+fn parse_day(day: &str) -> Result<usize, String> {
+    day.parse()
+        .ok()
+        .filter(|day| (1..=generate::DAYS).contains(day))
+        .ok_or_else(|| format!("Invalid day '{day}', expected 1-{}", generate::DAYS))
+}
+
+fn generate_input(args: &Args) -> Result<String, String> {
+    args.check_options(&["seed", "size"])?;
+
+    let [day] = args.positional.as_slice() else {
+        return Err(USAGE.into());
+    };
+    let day = parse_day(day)?;
+    let seed = args.option("seed")?.unwrap_or(0);
+    let size = match args.option("size")? {
+        Some(size) => size,
+        None => generate::default_size(day).unwrap(),
+    };
+
+    Ok(generate::generate(day, seed, size).unwrap())
+}
+
+fn extract_examples(args: &Args) -> Result<(), Box<dyn Error>> {
+    args.check_options(&[])?;
+
+    let [day, page_path] = args.positional.as_slice() else {
+        return Err(USAGE.into());
+    };
+    let day = parse_day(day)?;
+
+    let html = fs::read_to_string(page_path)?;
+    let examples = puzzle_page::extract_examples(&html)?;
+    if examples.is_empty() {
+        return Err(format!("No examples found in {page_path}").into());
+    }
+
+    let dir = Path::new("examples").join(format!("day{day}"));
+    let inputs = puzzle_page::write_examples(&dir, &examples).map_err(|err| err.to_string())?;
+    for path in inputs {
+        eprintln!("Wrote {} and its answers", path.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "fetch")]
+fn download_input(base_url: &str, day: usize, session: &str) -> Result<String, fetch::FetchError> {
+    use fetch::FetchError;
+    use std::time::Duration;
+
+    let agent = ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).redirects(0).build();
+    let mut request = agent.get(&fetch::input_url(base_url, day));
+    for (name, value) in fetch::request_headers(session) {
+        request = request.set(name, &value);
+    }
+    let response = request.call();
+
+    match response {
+        Ok(response) if response.status() == 200 => Ok(response.into_string()?),
+        Ok(response) => Err(FetchError::Status {
+            code: response.status(),
+            body: response.into_string().unwrap_or_default(),
+        }),
+        Err(ureq::Error::Status(code, response)) => {
+            Err(FetchError::Status { code, body: response.into_string().unwrap_or_default() })
+        }
+        Err(ureq::Error::Transport(err)) => Err(FetchError::Transport(err.to_string())),
+    }
+}
+
+#[cfg(not(feature = "fetch"))]
+fn download_input(
+    _base_url: &str,
+    _day: usize,
+    _session: &str,
+) -> Result<String, fetch::FetchError> {
+    let message = "aoc was built without the fetch feature; rerun with --features fetch";
+    Err(std::io::Error::new(std::io::ErrorKind::Unsupported, message).into())
+}
+
+fn fetch_input(args: &Args) -> Result<PathBuf, Box<dyn Error>> {
+    args.check_options(&["base-url"])?;
+
+    let [day] = args.positional.as_slice() else {
+        return Err(USAGE.into());
+    };
+    let day = parse_day(day)?;
+    let base_url: String = args.option("base-url")?.unwrap_or(fetch::DEFAULT_BASE_URL.into());
+
+    let path = fetch::cached_input(Path::new("inputs"), day, || {
+        download_input(&base_url, day, &fetch::find_session()?)
+    })
+    .map_err(|err| err.to_string())?;
+    Ok(path)
+}
+
+fn new_day(args: &Args) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    args.check_options(&["title", "page"])?;
+
+    let [day] = args.positional.as_slice() else {
+        return Err(USAGE.into());
+    };
+    let day = parse_day(day)?;
+    let title: String = args.option("title")?.ok_or_else(|| format!("Missing --title\n{USAGE}"))?;
+    let page = args.option::<String>("page")?.map(fs::read_to_string).transpose()?;
+
+    let paths = scaffold::new_day(Path::new("."), day, &title, page.as_deref())
+        .map_err(|err| err.to_string())?;
+    Ok(paths)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1);
+    let command = args.next();
+    let args = Args::parse(args)?;
+
+    match command.as_deref() {
+        Some("gen") => print!("{}", generate_input(&args)?),
+        Some("extract") => extract_examples(&args)?,
+        Some("fetch") => println!("{}", fetch_input(&args)?.display()),
+        Some("new") => {
+            for path in new_day(&args)? {
+                eprintln!("Created {}", path.display());
+            }
+        }
+        _ => return Err(USAGE.into()),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Args {
+        Args::parse(args.iter().map(|&arg| arg.to_string())).unwrap()
+    }
+
+    #[test]
+    fn generate_args() {
+        let input = generate_input(&args(&["7", "--seed", "3", "--size", "11"])).unwrap();
+        assert_eq!(generate::generate(7, 3, 11), Some(input));
+
+        assert_eq!(Err("Invalid day '13', expected 1-12".into()), generate_input(&args(&["13"])));
+        assert_eq!(
+            Err("Invalid value 'x' for --seed".into()),
+            generate_input(&args(&["1", "--seed", "x"]))
+        );
+        assert!(generate_input(&args(&["1", "--sead", "1"])).is_err());
+        assert!(Args::parse(["1".to_string(), "--seed".to_string()]).is_err());
+    }
+
+    #[cfg(feature = "fetch")]
+    mod fetch {
+        use super::*;
+        use advent_of_code_2025::fetch::USER_AGENT;
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+        use std::sync::{Arc, Mutex};
+        use std::thread;
+
+        // Serves each request with the given status and body, and records the request lines and
+        // headers
+        fn stub_server(
+            status: &'static str,
+            body: &'static str,
+        ) -> (String, Arc<Mutex<Vec<String>>>) {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}/", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let recorded = Arc::clone(&requests);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let request: String = BufReader::new(&stream)
+                        .lines()
+                        .map(Result::unwrap)
+                        .take_while(|line| !line.is_empty())
+                        .map(|line| line + "\r\n")
+                        .collect();
+                    recorded.lock().unwrap().push(request);
+
+                    let response = format!(
+                        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    stream.write_all(response.as_bytes()).unwrap();
+                }
+            });
+
+            (base_url, requests)
+        }
+
+        #[test]
+        fn download() {
+            let (base_url, requests) = stub_server("200 OK", "1\n2\n");
+            assert_eq!("1\n2\n", download_input(&base_url, 3, "abc123").unwrap());
+
+            let requests = requests.lock().unwrap();
+            assert!(
+                requests[0].starts_with("GET /2025/day/3/input HTTP/1.1\r\n"),
+                "{}",
+                requests[0]
+            );
+            let headers = requests[0].to_lowercase();
+            assert!(headers.contains("\r\ncookie: session=abc123\r\n"), "{headers}");
+            assert!(
+                headers.contains(&format!("\r\nuser-agent: {}\r\n", USER_AGENT.to_lowercase()))
+            );
+        }
+
+        #[test]
+        fn failed_download() {
+            let (base_url, _) = stub_server("400 Bad Request", "Please log in");
+            let err = download_input(&base_url, 1, "abc123").unwrap_err();
+            assert_eq!("Request failed with status 400: Please log in", err.to_string());
+        }
+    }
+}
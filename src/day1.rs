@@ -0,0 +1,156 @@
+//! Day 1: Secret Entrance
+//!
+//! <https://adventofcode.com/2025/day/1>
+
+use crate::Solution;
+use crate::params::{Param, Params};
+use std::str::FromStr;
+
+pub const DIAL_SIZE: Param<i64> = Param::new("dial_size", 100);
+pub const DIAL_START: Param<i64> = Param::new("dial_start", 50);
+
+#[derive(Debug, Clone, Copy)]
+struct Dial {
+    size: i64,
+    start: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Rotation {
+    pub direction: i64,
+    pub magnitude: i64,
+}
+
+pub fn parse(input: &str) -> Vec<Rotation> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let magnitude = i64::from_str(&line[1..]).expect("Invalid line");
+
+            let direction = match line.as_bytes()[0] {
+                b'L' => -1,
+                b'R' => 1,
+                _ => panic!("Invalid line {line}"),
+            };
+
+            Rotation { direction, magnitude }
+        })
+        .collect()
+}
+
+impl Dial {
+    // Number of times the dial ends a rotation pointing at 0
+    fn count_zero_stops(self, rotations: impl Iterator<Item = Rotation>) -> u64 {
+        let mut position = self.start;
+        let mut count = 0;
+
+        for rotation in rotations {
+            position = (position + rotation.direction * rotation.magnitude).rem_euclid(self.size);
+            if position == 0 {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    // Number of clicks during all rotations that leave the dial pointing at 0
+    fn count_zero_clicks(self, rotations: impl Iterator<Item = Rotation>) -> u64 {
+        let mut position = self.start;
+        let mut count = 0;
+
+        for rotation in rotations {
+            count += self.zero_clicks(position, rotation);
+            position = (position + rotation.direction * rotation.magnitude).rem_euclid(self.size);
+        }
+
+        count
+    }
+
+    // Counts the multiples of the dial size in the range of positions passed through, not
+    // including the starting position. With the position in 0..size:
+    //   Right: multiples in (p, p + m] = floor((p + m) / size)
+    //   Left:  multiples in [p - m, p - 1] = floor((p - 1) / size) - floor((p - m - 1) / size)
+    fn zero_clicks(self, position: i64, rotation: Rotation) -> u64 {
+        let Rotation { direction, magnitude } = rotation;
+        let count = if direction > 0 {
+            (position + magnitude).div_euclid(self.size)
+        } else {
+            (position - 1).div_euclid(self.size) - (position - magnitude - 1).div_euclid(self.size)
+        };
+
+        count as u64
+    }
+}
+
+fn dial(params: &Params) -> Dial {
+    let dial = Dial { size: params.get(&DIAL_SIZE), start: params.get(&DIAL_START) };
+    assert!(dial.size > 0, "Dial size must be positive, got {}", dial.size);
+    dial
+}
+
+pub fn part1(rotations: &[Rotation], params: &Params) -> u64 {
+    dial(params).count_zero_stops(rotations.iter().copied())
+}
+
+pub fn part2(rotations: &[Rotation], params: &Params) -> u64 {
+    dial(params).count_zero_clicks(rotations.iter().copied())
+}
+
+pub const SOLUTION: Solution = Solution {
+    day: 1,
+    params: &[&DIAL_SIZE, &DIAL_START],
+    part1: |input, params| part1(&parse(input), params).to_string(),
+    part2: |input, params| part2(&parse(input), params).to_string(),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    crate::aoc_test!(day1);
+
+    #[test]
+    fn part_2_large_rotation() {
+        assert_eq!(10_000_001, part2(&parse("R1000000050"), &Params::new()));
+        assert_eq!(10_000_000, part2(&parse("L1000000049"), &Params::new()));
+    }
+
+    fn simulate_zero_clicks(dial: Dial, rotations: &[Rotation]) -> u64 {
+        let mut position = dial.start;
+        let mut count = 0;
+
+        for rotation in rotations {
+            for _ in 0..rotation.magnitude {
+                position = (position + rotation.direction).rem_euclid(dial.size);
+                if position == 0 {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    #[test]
+    fn part_2_matches_simulation() {
+        let mut rng = Rng::new(2025);
+        let mut next = |bound: u64| rng.below(bound) as i64;
+
+        for _ in 0..500 {
+            let size = next(20) + 1;
+            let dial = Dial { size, start: next(size as u64) };
+            let rotations: Vec<_> = (0..next(10))
+                .map(|_| Rotation { direction: 2 * next(2) - 1, magnitude: next(60) })
+                .collect();
+
+            assert_eq!(
+                simulate_zero_clicks(dial, &rotations),
+                dial.count_zero_clicks(rotations.iter().copied()),
+                "{dial:?} {rotations:?}"
+            );
+        }
+    }
+}
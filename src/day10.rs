@@ -0,0 +1,445 @@
+//! Day 10: Factory
+//!
+//! <https://adventofcode.com/2025/day/10>
+//!
+//! This solution is absolutely terrible but it finishes in less than 30 seconds (by using multiple
+//! threads).
+//!
+//! I'm sure there's a fancy linear algebra solution to this problem, but this solves it using
+//! a search+pruning approach.
+
+use crate::Solution;
+use rustc_hash::FxHashSet;
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::{cmp, thread};
+use winnow::ascii::{digit1, newline};
+use winnow::combinator::{delimited, empty, fail, opt, repeat, separated, terminated};
+use winnow::dispatch;
+use winnow::prelude::*;
+use winnow::token::any;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    pub indicators: Vec<bool>,
+    pub buttons: Vec<Vec<usize>>,
+    pub joltage_reqs: Vec<i32>,
+}
+
+fn parse_num<T: FromStr>(input: &mut &str) -> winnow::Result<T> {
+    digit1.parse_to().parse_next(input)
+}
+
+fn parse_indicator(input: &mut &str) -> winnow::Result<bool> {
+    dispatch! { any;
+        '.' => empty.value(false),
+        '#' => empty.value(true),
+        _ => fail,
+    }
+    .parse_next(input)
+}
+
+fn parse_button(input: &mut &str) -> winnow::Result<Vec<usize>> {
+    delimited('(', separated(1.., parse_num::<usize>, ','), ')').parse_next(input)
+}
+
+fn parse_joltage_reqs(input: &mut &str) -> winnow::Result<Vec<i32>> {
+    delimited('{', separated(1.., parse_num::<i32>, ','), '}').parse_next(input)
+}
+
+fn parse_machine(input: &mut &str) -> winnow::Result<Machine> {
+    let indicators = delimited('[', repeat(1.., parse_indicator), ']').parse_next(input)?;
+    ' '.parse_next(input)?;
+    let buttons = separated(1.., parse_button, ' ').parse_next(input)?;
+    ' '.parse_next(input)?;
+    let joltage_reqs = parse_joltage_reqs.parse_next(input)?;
+
+    Ok(Machine { indicators, buttons, joltage_reqs })
+}
+
+fn parse_machines(input: &mut &str) -> winnow::Result<Vec<Machine>> {
+    terminated(separated(1.., parse_machine, newline), opt(newline)).parse_next(input)
+}
+
+pub fn parse(input: &str) -> Vec<Machine> {
+    parse_machines.parse(input).expect("Failed to parse input")
+}
+
+struct QueueEntry {
+    state: Vec<bool>,
+    len: u32,
+}
+
+pub fn part1(machines: &[Machine]) -> u32 {
+    machines
+        .iter()
+        .map(|machine| {
+            let mut queue = VecDeque::new();
+            queue.push_back(QueueEntry { state: vec![false; machine.indicators.len()], len: 0 });
+
+            let mut visited = FxHashSet::default();
+            visited.insert(vec![false; machine.indicators.len()]);
+
+            while let Some(QueueEntry { state, len }) = queue.pop_front() {
+                for button in &machine.buttons {
+                    let mut new_state = state.clone();
+                    for &indicator in button {
+                        new_state[indicator] = !new_state[indicator];
+                    }
+
+                    if new_state == machine.indicators {
+                        return len + 1;
+                    }
+
+                    if !visited.contains(&new_state) {
+                        visited.insert(new_state.clone());
+                        queue.push_back(QueueEntry { state: new_state, len: len + 1 });
+                    }
+                }
+            }
+
+            panic!("Queue emptied without reaching the target indicator state")
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone)]
+struct Equation {
+    indices: Vec<usize>,
+    joltage_idx: usize,
+}
+
+fn generate_equations(machine: &Machine) -> Vec<Equation> {
+    let mut equations: Vec<_> = machine
+        .joltage_reqs
+        .iter()
+        .enumerate()
+        .map(|(joltage_idx, _)| Equation { indices: Vec::new(), joltage_idx })
+        .collect();
+
+    for (i, button) in machine.buttons.iter().enumerate() {
+        for &idx in button {
+            equations[idx].indices.push(i);
+        }
+    }
+
+    equations
+}
+
+pub fn part2(machines: &[Machine]) -> u32 {
+    let mut threads = Vec::new();
+    for machine in machines.iter().cloned() {
+        threads.push(thread::spawn(move || {
+            let mut equations = generate_equations(&machine);
+            equations.sort_by_key(|equation| equation.indices.len());
+            for equation in &mut equations {
+                equation
+                    .indices
+                    .sort_by_key(|&button_idx| Reverse(machine.buttons[button_idx].len()));
+            }
+
+            let mut min_presses = machine.joltage_reqs.iter().copied().sum::<i32>() as u32;
+            find_solutions(
+                0,
+                machine.buttons.clone(),
+                &machine.joltage_reqs,
+                &equations,
+                &mut min_presses,
+            );
+
+            min_presses
+        }));
+    }
+
+    threads.into_iter().map(|thread| thread.join().unwrap()).sum()
+}
+
+fn find_solutions(
+    presses: u32,
+    buttons: Vec<Vec<usize>>,
+    joltages: &[i32],
+    equations: &[Equation],
+    min_presses: &mut u32,
+) {
+    if equations.is_empty() {
+        *min_presses = cmp::min(*min_presses, presses);
+        return;
+    }
+
+    if presses + joltages.iter().copied().max().unwrap() as u32 >= *min_presses {
+        return;
+    }
+
+    let button_indices: Vec<_> = equations[0]
+        .indices
+        .iter()
+        .copied()
+        .filter(|&button_idx| !buttons[button_idx].is_empty())
+        .collect();
+    if button_indices.is_empty() {
+        if joltages[equations[0].joltage_idx] == 0 {
+            find_solutions(presses, buttons, joltages, &equations[1..], min_presses);
+        }
+        return;
+    }
+
+    test_button(&button_indices, presses, &buttons, joltages, equations, min_presses);
+}
+
+fn test_button(
+    button_indices: &[usize],
+    presses: u32,
+    buttons: &[Vec<usize>],
+    joltages: &[i32],
+    equations: &[Equation],
+    min_presses: &mut u32,
+) {
+    if button_indices.is_empty() {
+        let mut new_buttons = buttons.to_vec();
+        for &button_idx in &equations[0].indices {
+            new_buttons[button_idx].clear();
+        }
+
+        find_solutions(presses, new_buttons, joltages, &equations[1..], min_presses);
+        return;
+    }
+
+    let button_idx = button_indices[0];
+
+    let mut min_possible = 0;
+    if button_indices.len() == 1 {
+        min_possible = joltages[equations[0].joltage_idx];
+
+        for &joltage_idx in &buttons[button_idx] {
+            if let Some(equation) =
+                equations[1..].iter().find(|equation| equation.joltage_idx == joltage_idx)
+                && equation.indices.iter().all(|&button_idx| {
+                    buttons[button_idx].is_empty() || equations[0].indices.contains(&button_idx)
+                })
+            {
+                min_possible = cmp::max(min_possible, joltages[equation.joltage_idx]);
+            }
+        }
+    }
+
+    let max_possible =
+        buttons[button_idx].iter().map(|&joltage_idx| joltages[joltage_idx]).min().unwrap_or(0);
+    let max_possible = cmp::min(max_possible, (*min_presses as i32) - (presses as i32) - 1);
+
+    for button_presses in (min_possible..=max_possible).rev() {
+        if presses + (button_presses as u32) >= *min_presses {
+            break;
+        }
+
+        let new_presses = presses + button_presses as u32;
+        let mut new_joltages = joltages.to_vec();
+
+        for &joltage_idx in &buttons[button_idx] {
+            new_joltages[joltage_idx] -= button_presses;
+            assert!(new_joltages[joltage_idx] >= 0);
+        }
+
+        test_button(
+            &button_indices[1..],
+            new_presses,
+            buttons,
+            &new_joltages,
+            equations,
+            min_presses,
+        );
+    }
+}
+
+pub const SOLUTION: Solution = Solution {
+    day: 10,
+    params: &[],
+    part1: |input, _| part1(&parse(input)).to_string(),
+    part2: |input, _| part2(&parse(input)).to_string(),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential;
+    use crate::rng::Rng;
+    use std::fmt::{self, Display};
+
+    crate::aoc_test!(day10);
+
+    // Requirements are derived from how many times each button is pressed, so that every case has
+    // a solution
+    #[derive(Debug, Clone)]
+    struct MachineCase {
+        counters: usize,
+        buttons: Vec<Vec<usize>>,
+        toggled: Vec<bool>,
+        presses: Vec<i32>,
+    }
+
+    impl MachineCase {
+        fn requirements(&self) -> (Vec<bool>, Vec<i32>) {
+            let mut indicators = vec![false; self.counters];
+            let mut joltages = vec![0; self.counters];
+            for (i, button) in self.buttons.iter().enumerate() {
+                for &counter in button {
+                    indicators[counter] ^= self.toggled[i];
+                    joltages[counter] += self.presses[i];
+                }
+            }
+            (indicators, joltages)
+        }
+
+        // Real machines never start with their indicators already in the target state
+        fn is_valid(&self) -> bool {
+            self.requirements().0.contains(&true)
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Case {
+        machines: Vec<MachineCase>,
+    }
+
+    impl Display for Case {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for machine in &self.machines {
+                let (indicators, joltages) = machine.requirements();
+                let indicators: String =
+                    indicators.iter().map(|&on| if on { '#' } else { '.' }).collect();
+                let buttons: Vec<_> = machine
+                    .buttons
+                    .iter()
+                    .map(|button| {
+                        let counters: Vec<_> = button.iter().map(usize::to_string).collect();
+                        format!("({})", counters.join(","))
+                    })
+                    .collect();
+                let joltages: Vec<_> = joltages.iter().map(i32::to_string).collect();
+                writeln!(f, "[{indicators}] {} {{{}}}", buttons.join(" "), joltages.join(","))?;
+            }
+            Ok(())
+        }
+    }
+
+    fn random_machine(rng: &mut Rng) -> MachineCase {
+        loop {
+            let counters = rng.range(2..=4) as usize;
+            let buttons_len = rng.range(1..=4) as usize;
+            let buttons = (0..buttons_len)
+                .map(|_| {
+                    let button: Vec<_> = (0..counters).filter(|_| rng.chance(1, 2)).collect();
+                    if button.is_empty() { vec![rng.index(counters)] } else { button }
+                })
+                .collect();
+            let toggled = (0..buttons_len).map(|_| rng.chance(1, 2)).collect();
+            let presses = (0..buttons_len).map(|_| rng.range(0..=3) as i32).collect();
+
+            let machine = MachineCase { counters, buttons, toggled, presses };
+            if machine.is_valid() {
+                return machine;
+            }
+        }
+    }
+
+    fn random_case(rng: &mut Rng) -> Case {
+        Case { machines: (0..rng.range(1..=3)).map(|_| random_machine(rng)).collect() }
+    }
+
+    // Candidates with a machine or button removed, a button no longer toggled, or a button
+    // pressed fewer times
+    fn shrink_case(case: &Case) -> Vec<Case> {
+        let mut candidates = vec![];
+        for i in 0..case.machines.len() {
+            if case.machines.len() > 1 {
+                let mut machines = case.machines.clone();
+                machines.remove(i);
+                candidates.push(Case { machines });
+            }
+
+            let machine = &case.machines[i];
+            let mut shrunk = vec![];
+            for j in 0..machine.buttons.len() {
+                if machine.buttons.len() > 1 {
+                    let mut machine = machine.clone();
+                    machine.buttons.remove(j);
+                    machine.toggled.remove(j);
+                    machine.presses.remove(j);
+                    shrunk.push(machine);
+                }
+                if machine.toggled[j] {
+                    let mut machine = machine.clone();
+                    machine.toggled[j] = false;
+                    shrunk.push(machine);
+                }
+                if machine.presses[j] > 0 {
+                    let mut machine = machine.clone();
+                    machine.presses[j] -= 1;
+                    shrunk.push(machine);
+                }
+            }
+
+            candidates.extend(shrunk.into_iter().filter(MachineCase::is_valid).map(|machine| {
+                let mut machines = case.machines.clone();
+                machines[i] = machine;
+                Case { machines }
+            }));
+        }
+
+        candidates
+    }
+
+    // Tries every subset of buttons for part 1, and every combination of press counts that
+    // doesn't overshoot a requirement for part 2
+    fn brute_force(case: &Case) -> (u32, u32) {
+        fn min_presses(buttons: &[Vec<usize>], joltages: &mut [i32], presses: u32) -> Option<u32> {
+            let Some((button, rest)) = buttons.split_first() else {
+                return joltages.iter().all(|&joltage| joltage == 0).then_some(presses);
+            };
+
+            let max = button.iter().map(|&counter| joltages[counter]).min().unwrap();
+            (0..=max)
+                .filter_map(|count| {
+                    button.iter().for_each(|&counter| joltages[counter] -= count);
+                    let result = min_presses(rest, joltages, presses + count as u32);
+                    button.iter().for_each(|&counter| joltages[counter] += count);
+                    result
+                })
+                .min()
+        }
+
+        case.machines.iter().fold((0, 0), |(part_1, part_2), machine| {
+            let (indicators, mut joltages) = machine.requirements();
+
+            let fewest_toggles = (0_u32..1 << machine.buttons.len())
+                .filter(|&subset| {
+                    let mut state = vec![false; machine.counters];
+                    for (i, button) in machine.buttons.iter().enumerate() {
+                        if subset & (1 << i) != 0 {
+                            button.iter().for_each(|&counter| state[counter] ^= true);
+                        }
+                    }
+                    state == indicators
+                })
+                .map(u32::count_ones)
+                .min()
+                .unwrap();
+            let fewest_presses = min_presses(&machine.buttons, &mut joltages, 0).unwrap();
+
+            (part_1 + fewest_toggles, part_2 + fewest_presses)
+        })
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        differential::check(
+            differential::cases(2000, random_case),
+            |case| {
+                let machines = parse(&case.to_string());
+                (part1(&machines), part2(&machines))
+            },
+            brute_force,
+            shrink_case,
+        );
+    }
+}
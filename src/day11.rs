@@ -0,0 +1,100 @@
+//! Day 11: Reactor
+//!
+//! <https://adventofcode.com/2025/day/11>
+
+use crate::Solution;
+use crate::graph::Graph;
+use crate::graph_export;
+use crate::paths::{self, PathQuery};
+use std::error::Error;
+use winnow::ascii::newline;
+use winnow::combinator::{opt, separated, separated_pair, terminated};
+use winnow::prelude::*;
+use winnow::token::take_while;
+
+#[derive(Debug, Clone)]
+struct Node<'a> {
+    name: &'a str,
+    edges: Vec<&'a str>,
+}
+
+fn parse_name<'a>(input: &mut &'a str) -> winnow::Result<&'a str> {
+    take_while(1.., |c: char| !c.is_whitespace() && c != ':').parse_next(input)
+}
+
+fn parse_edges<'a>(input: &mut &'a str) -> winnow::Result<Vec<&'a str>> {
+    separated(1.., parse_name, ' ').parse_next(input)
+}
+
+fn parse_node<'a>(input: &mut &'a str) -> winnow::Result<Node<'a>> {
+    let (name, edges) = separated_pair(parse_name, ": ", parse_edges).parse_next(input)?;
+
+    Ok(Node { name, edges })
+}
+
+fn parse_nodes<'a>(input: &mut &'a str) -> winnow::Result<Vec<Node<'a>>> {
+    terminated(separated(1.., parse_node, newline), opt(newline)).parse_next(input)
+}
+
+pub fn parse(input: &str) -> Graph<'_> {
+    let nodes = parse_nodes.parse(input).expect("Failed to parse input");
+    Graph::from_adjacency(nodes.iter().map(|node| (node.name, node.edges.iter().copied())))
+}
+
+fn part_1_query() -> PathQuery<'static> {
+    PathQuery::new("you", "out")
+}
+
+fn part_2_query() -> PathQuery<'static> {
+    PathQuery { required: vec!["dac", "fft"], ..PathQuery::new("svr", "out") }
+}
+
+pub fn part1(graph: &Graph<'_>) -> u128 {
+    paths::count_paths(graph, &part_1_query()).expect("Failed to count paths")
+}
+
+pub fn part2(graph: &Graph<'_>) -> u128 {
+    paths::count_paths(graph, &part_2_query()).expect("Failed to count paths")
+}
+
+/// Renders the device graph as `dot` or `mermaid`, with the part 2 waypoints highlighted and each
+/// device annotated with its path count. Graphs without an `svr` device use the part 1 query
+/// instead. The path counts are left out if they can't be computed, e.g. because the graph has a
+/// cycle or is missing the query's devices, since that's when a picture of the graph helps most.
+pub fn export_graph(graph: &Graph<'_>, format: &str) -> Result<String, Box<dyn Error>> {
+    let query = if graph.id("svr").is_some() { part_2_query() } else { part_1_query() };
+    let counts = paths::path_counts(graph, &query).ok();
+
+    match format {
+        "dot" => Ok(graph_export::to_dot(graph, &query, counts.as_ref())),
+        "mermaid" => Ok(graph_export::to_mermaid(graph, &query, counts.as_ref())),
+        _ => Err(format!("Unknown export format '{format}', expected 'dot' or 'mermaid'").into()),
+    }
+}
+
+pub const SOLUTION: Solution = Solution {
+    day: 11,
+    params: &[],
+    part1: |input, _| part1(&parse(input)).to_string(),
+    part2: |input, _| part2(&parse(input)).to_string(),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_test!(day11);
+
+    #[test]
+    fn export_without_counts() {
+        let graph = parse("you: a out\na: out\n");
+        let mermaid = export_graph(&graph, "mermaid").unwrap();
+        assert!(mermaid.contains("n0[\"you<br/>1\"]"), "{mermaid}");
+
+        // The cycle between a and b makes the paths uncountable, but the graph still renders
+        let graph = parse("you: a out\na: b\nb: a out\n");
+        let mermaid = export_graph(&graph, "mermaid").unwrap();
+        assert!(mermaid.contains("n0[\"you\"]"), "{mermaid}");
+        assert!(mermaid.contains("n3 --> n1"), "{mermaid}");
+    }
+}
@@ -0,0 +1,121 @@
+//! Day 12: Christmas Tree Farm
+//!
+//! <https://adventofcode.com/2025/day/12>
+//!
+//! This is implemented by simply checking if the total number of cells occupied by all required
+//! shapes is less than or equal to the number of cells in the region. This does not work for the
+//! sample input but it works for the actual input (at least for my input).
+//!
+//! Implementing an actual packing solution is probably intractable in the general case, though it
+//! might be possible by specializing the solution to the specific puzzle input.
+
+use crate::Solution;
+use std::iter;
+use winnow::ascii::{digit1, newline};
+use winnow::combinator::{empty, fail, opt, preceded, separated, separated_pair, terminated};
+use winnow::dispatch;
+use winnow::prelude::*;
+use winnow::token::any;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shape {
+    pub occupied: [[bool; 3]; 3],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub width: usize,
+    pub height: usize,
+    pub required_shapes: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Farm {
+    pub shapes: Vec<Shape>,
+    pub regions: Vec<Region>,
+}
+
+fn parse_shape_space(input: &mut &str) -> winnow::Result<bool> {
+    dispatch! { any;
+        '.' => empty.value(false),
+        '#' => empty.value(true),
+        _ => fail,
+    }
+    .parse_next(input)
+}
+
+fn parse_shape_row(input: &mut &str) -> winnow::Result<[bool; 3]> {
+    terminated((parse_shape_space, parse_shape_space, parse_shape_space), newline)
+        .parse_next(input)
+        .map(|(a, b, c)| [a, b, c])
+}
+
+fn parse_shape(input: &mut &str) -> winnow::Result<Shape> {
+    preceded((digit1, ":", newline), (parse_shape_row, parse_shape_row, parse_shape_row))
+        .parse_next(input)
+        .map(|(a, b, c)| Shape { occupied: [a, b, c] })
+}
+
+fn parse_region_size(input: &mut &str) -> winnow::Result<(usize, usize)> {
+    separated_pair(digit1.parse_to(), 'x', digit1.parse_to()).parse_next(input)
+}
+
+fn parse_required_shapes(input: &mut &str) -> winnow::Result<Vec<usize>> {
+    separated(1.., digit1.parse_to::<usize>(), ' ').parse_next(input)
+}
+
+fn parse_region(input: &mut &str) -> winnow::Result<Region> {
+    separated_pair(parse_region_size, ": ", parse_required_shapes)
+        .parse_next(input)
+        .map(|((width, height), required_shapes)| Region { width, height, required_shapes })
+}
+
+fn parse_farm(input: &mut &str) -> winnow::Result<Farm> {
+    terminated(
+        separated_pair(
+            separated(1.., parse_shape, newline),
+            newline,
+            separated(1.., parse_region, newline),
+        ),
+        opt(newline),
+    )
+    .parse_next(input)
+    .map(|(shapes, regions)| Farm { shapes, regions })
+}
+
+pub fn parse(input: &str) -> Farm {
+    parse_farm.parse(input).expect("Failed to parse input")
+}
+
+/// There is no part 2 on the last day.
+pub fn part1(farm: &Farm) -> usize {
+    let shape_cells_occupied: Vec<usize> = farm
+        .shapes
+        .iter()
+        .map(|shape| shape.occupied.iter().map(|row| row.iter().filter(|&&b| b).count()).sum())
+        .collect();
+
+    farm.regions
+        .iter()
+        .filter(|region| {
+            let region_area = region.width * region.height;
+            let shape_total_area: usize = iter::zip(&region.required_shapes, &shape_cells_occupied)
+                .map(|(required, &cells_occupied)| required * cells_occupied)
+                .sum();
+
+            shape_total_area <= region_area
+        })
+        .count()
+}
+
+pub const SOLUTION: Solution = Solution {
+    day: 12,
+    params: &[],
+    part1: |input, _| part1(&parse(input)).to_string(),
+    part2: |_, _| String::new(),
+};
+
+#[cfg(test)]
+mod tests {
+    crate::aoc_test!(day12);
+}
@@ -0,0 +1,168 @@
+//! Day 2: Gift Shop
+//!
+//! <https://adventofcode.com/2025/day/2>
+//!
+//! Rather than checking every ID in each range, this generates the invalid IDs directly. A number
+//! with `len` digits that consists of a `block_len`-digit block repeated is the block times the
+//! multiplier 10...010...01 (`(10^len - 1) / (10^block_len - 1)`), so the invalid IDs in a range
+//! form an arithmetic series over the blocks that keep the product within the range.
+//!
+//! For part 2, a number can be made of repeated blocks of several different lengths (e.g. 111111
+//! is 1 x 6, 11 x 3 and 111 x 2), so each number is only counted for the shortest block length
+//! that produces it. Every other block length that produces it is a multiple of the shortest one.
+
+use crate::Solution;
+use std::cmp;
+
+fn digits(value: u128) -> u32 {
+    value.checked_ilog10().unwrap_or(0) + 1
+}
+
+// Sum of the numbers in [start, end] with `len` digits that consist of a `block_len`-digit block
+// repeated `len / block_len` times, or `None` if the sum overflows
+fn sum_repeated_blocks(start: u128, end: u128, len: u32, block_len: u32) -> Option<u128> {
+    // Built up one block at a time, since 10^len may not fit even when the multiplier does
+    let block_limit = 10_u128.pow(block_len);
+    let multiplier = (0..len / block_len)
+        .try_fold(0_u128, |multiplier, _| multiplier.checked_mul(block_limit)?.checked_add(1))?;
+
+    let block_min = cmp::max(block_limit / 10, start.div_ceil(multiplier));
+    let block_max = cmp::min(block_limit - 1, end / multiplier);
+    if block_min > block_max {
+        return Some(0);
+    }
+
+    // One of the sum and count of the blocks is even
+    let block_sum = (block_min + block_max).checked_mul(block_max - block_min + 1)? / 2;
+    multiplier.checked_mul(block_sum)
+}
+
+fn sum_invalid_part_1(start: u128, end: u128, len: u32) -> Option<u128> {
+    if !len.is_multiple_of(2) {
+        return Some(0);
+    }
+
+    sum_repeated_blocks(start, end, len, len / 2)
+}
+
+fn sum_invalid_part_2(start: u128, end: u128, len: u32) -> Option<u128> {
+    let block_lens: Vec<_> = (1..len).filter(|&block_len| len.is_multiple_of(block_len)).collect();
+
+    // shortest_sums[i] is the sum of the numbers whose shortest block length is block_lens[i]
+    let mut shortest_sums: Vec<u128> = Vec::with_capacity(block_lens.len());
+    for (i, &block_len) in block_lens.iter().enumerate() {
+        let longer_than_shortest: u128 = block_lens[..i]
+            .iter()
+            .zip(&shortest_sums)
+            .filter(|&(&shorter_len, _)| block_len.is_multiple_of(shorter_len))
+            .map(|(_, &sum)| sum)
+            .sum();
+
+        shortest_sums.push(sum_repeated_blocks(start, end, len, block_len)? - longer_than_shortest);
+    }
+
+    shortest_sums.into_iter().try_fold(0_u128, u128::checked_add)
+}
+
+pub fn parse(input: &str) -> Vec<(u128, u128)> {
+    input
+        .trim()
+        .split(',')
+        .map(|pair| {
+            let (first, second) = pair.split_once('-').expect("Split on '-'");
+            let start: u128 = first.trim().parse().expect("Parse start");
+            let end: u128 = second.trim().parse().expect("Parse end");
+            (start, end)
+        })
+        .collect()
+}
+
+// Sum of the invalid IDs, or `None` if it doesn't fit in a u128
+fn solve<const PART2: bool>(ranges: &[(u128, u128)]) -> Option<u128> {
+    let sum_invalid = if PART2 { sum_invalid_part_2 } else { sum_invalid_part_1 };
+
+    ranges
+        .iter()
+        .flat_map(|&(start, end)| {
+            (digits(start)..=digits(end)).map(move |len| sum_invalid(start, end, len))
+        })
+        .try_fold(0_u128, |sum, range_sum| sum.checked_add(range_sum?))
+}
+
+pub fn part1(ranges: &[(u128, u128)]) -> u128 {
+    solve::<false>(ranges).expect("Sum of invalid IDs does not fit in 128 bits")
+}
+
+pub fn part2(ranges: &[(u128, u128)]) -> u128 {
+    solve::<true>(ranges).expect("Sum of invalid IDs does not fit in 128 bits")
+}
+
+pub const SOLUTION: Solution = Solution {
+    day: 2,
+    params: &[],
+    part1: |input, _| part1(&parse(input)).to_string(),
+    part2: |input, _| part2(&parse(input)).to_string(),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_test!(day2);
+
+    fn invalid_id_part_1(value: &u128) -> bool {
+        let s = value.to_string();
+        let bytes = s.as_bytes();
+
+        s.len().is_multiple_of(2) && (0..s.len() / 2).all(|i| bytes[i] == bytes[i + s.len() / 2])
+    }
+
+    fn invalid_id_part_2(value: &u128) -> bool {
+        let s = value.to_string();
+        let bytes = s.as_bytes();
+
+        (1..=s.len() / 2).any(|sub_len| {
+            s.len().is_multiple_of(sub_len)
+                && (0..sub_len)
+                    .all(|i| (i + sub_len..s.len()).step_by(sub_len).all(|j| bytes[i] == bytes[j]))
+        })
+    }
+
+    #[test]
+    fn matches_scan() {
+        for (start, end) in [(1, 20_000), (95, 1_234_567), (999_990, 1_000_100)] {
+            let input = format!("{start}-{end}");
+            assert_eq!(
+                (start..=end).filter(invalid_id_part_1).sum::<u128>(),
+                part1(&parse(&input))
+            );
+            assert_eq!(
+                (start..=end).filter(invalid_id_part_2).sum::<u128>(),
+                part2(&parse(&input))
+            );
+        }
+    }
+
+    #[test]
+    fn huge_range() {
+        // Every 20-digit invalid ID, which would take far too long to scan
+        assert_eq!(
+            495000000004499999995500000000,
+            part1(&parse("10000000000000000000-99999999999999999999"))
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        // 10^39 doesn't fit in a u128, but 39-digit invalid IDs do
+        let ones = "1".repeat(39);
+        let ranges = parse(&format!("{ones}-{ones}"));
+        assert_eq!(Some(0), solve::<false>(&ranges));
+        assert_eq!(ones.parse().ok(), solve::<true>(&ranges));
+
+        let ranges = parse(&format!("{}-{}", 10_u128.pow(38), u128::MAX));
+        assert_eq!(None, solve::<true>(&ranges));
+        let ranges = parse(&format!("{}-{}", 10_u128.pow(37), "9".repeat(38)));
+        assert_eq!(None, solve::<false>(&ranges));
+    }
+}
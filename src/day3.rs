@@ -0,0 +1,133 @@
+//! Day 3: Lobby
+//!
+//! <https://adventofcode.com/2025/day/3>
+
+use crate::Solution;
+use crate::params::{Param, Params};
+use std::fmt::{self, Display};
+use std::iter;
+
+pub const PART_1_BATTERIES: Param<usize> = Param::new("part_1_batteries", 2);
+pub const PART_2_BATTERIES: Param<usize> = Param::new("part_2_batteries", 12);
+
+// Big-endian decimal digits, so that joltages with any number of batteries can be represented
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Joltage(pub Vec<u8>);
+
+impl Joltage {
+    /// The joltage as an integer, or `None` if it does not fit in a `u64`.
+    pub fn value(&self) -> Option<u64> {
+        self.0
+            .iter()
+            .try_fold(0_u64, |value, &digit| value.checked_mul(10)?.checked_add(digit.into()))
+    }
+
+    fn add(&mut self, other: &Self) {
+        if self.0.len() < other.0.len() {
+            let padding = other.0.len() - self.0.len();
+            self.0.splice(0..0, iter::repeat_n(0, padding));
+        }
+
+        let mut carry = 0;
+        for i in 0..self.0.len() {
+            let self_idx = self.0.len() - 1 - i;
+            let digit = if i < other.0.len() { other.0[other.0.len() - 1 - i] } else { 0 };
+
+            let total = self.0[self_idx] + digit + carry;
+            self.0[self_idx] = total % 10;
+            carry = total / 10;
+        }
+
+        if carry != 0 {
+            self.0.insert(0, carry);
+        }
+    }
+}
+
+impl Display for Joltage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(value) = self.value() {
+            return write!(f, "{value}");
+        }
+
+        // Too large for u64, so there must be a non-zero digit; skip any zero padding before it
+        let start = self.0.iter().position(|&digit| digit != 0).unwrap();
+        for &digit in &self.0[start..] {
+            write!(f, "{digit}")?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn parse(input: &str) -> Vec<Vec<u8>> {
+    input.lines().map(|line| line.as_bytes().iter().map(|&c| c - b'0').collect()).collect()
+}
+
+/// Sum of the largest joltages that each bank can produce by turning on `batteries_len` batteries.
+pub fn max_joltage_sum(banks: &[Vec<u8>], batteries_len: usize) -> Joltage {
+    let mut sum = Joltage::default();
+    for batteries in banks {
+        sum.add(&find_max_joltage(batteries, batteries_len));
+    }
+
+    sum
+}
+
+pub fn part1(banks: &[Vec<u8>], params: &Params) -> Joltage {
+    max_joltage_sum(banks, params.get(&PART_1_BATTERIES))
+}
+
+pub fn part2(banks: &[Vec<u8>], params: &Params) -> Joltage {
+    max_joltage_sum(banks, params.get(&PART_2_BATTERIES))
+}
+
+// Largest subsequence of the given length, found with a monotonic stack: each smaller digit is
+// popped when a larger digit arrives, as long as enough digits remain to reach the length
+fn find_max_joltage(batteries: &[u8], len: usize) -> Joltage {
+    assert!(len <= batteries.len());
+
+    let mut removals = batteries.len() - len;
+    let mut stack = Vec::with_capacity(batteries.len());
+    for &digit in batteries {
+        while removals > 0 && stack.last().is_some_and(|&top| top < digit) {
+            stack.pop();
+            removals -= 1;
+        }
+
+        stack.push(digit);
+    }
+
+    stack.truncate(len);
+    Joltage(stack)
+}
+
+pub const SOLUTION: Solution = Solution {
+    day: 3,
+    params: &[&PART_1_BATTERIES, &PART_2_BATTERIES],
+    part1: |input, params| part1(&parse(input), params).to_string(),
+    part2: |input, params| part2(&parse(input), params).to_string(),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_test!(day3);
+
+    #[test]
+    fn long_joltages() {
+        let input = "9".repeat(40) + "1" + &"9".repeat(20) + "\n" + &"5".repeat(50);
+        let expected = "1".to_string() + &"5".repeat(49) + "4";
+        assert_eq!(expected, max_joltage_sum(&parse(&input), 50).to_string());
+        assert_eq!(None, max_joltage_sum(&parse(&input), 50).value());
+    }
+
+    #[test]
+    fn many_banks() {
+        let input = format!("{}\n", "9".repeat(100)).repeat(200);
+        let banks = parse(&input);
+        assert_eq!(Some(19800), part1(&banks, &Params::new()).value());
+        assert_eq!(Some(199_999_999_999_800), part2(&banks, &Params::new()).value());
+    }
+}
@@ -0,0 +1,188 @@
+//! Day 4: Printing Department
+//!
+//! <https://adventofcode.com/2025/day/4>
+//!
+//! Part 2 is simulated in rounds, where each round removes every roll that was accessible at the
+//! start of the round. Rather than rescanning the grid each round, this keeps a grid of neighbor
+//! counts and a worklist of rolls that became accessible, so the total work is proportional to
+//! the number of rolls removed. The final number of rolls removed doesn't depend on removal order.
+
+use crate::Solution;
+use crate::params::{Param, Params};
+use crate::{Grid2D, Point2D};
+
+type Point = Point2D<usize>;
+
+// Rolls with fewer than this many neighbors are accessible
+pub const NEIGHBOR_THRESHOLD: Param<u8> = Param::new("neighbor_threshold", 4);
+
+pub fn parse(input: &str) -> Grid2D<bool> {
+    Grid2D(
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().map(|c| c == '@').collect())
+            .collect(),
+    )
+}
+
+fn neighbors(grid: &Grid2D<bool>, point: Point) -> impl Iterator<Item = Point> + use<> {
+    let (rows, cols) = (grid.rows(), grid.cols());
+
+    (-1..=1)
+        .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+        .filter(|&(dx, dy)| dx != 0 || dy != 0)
+        .filter_map(move |(dx, dy)| {
+            let x = point.x.checked_add_signed(dx)?;
+            let y = point.y.checked_add_signed(dy)?;
+            (x < cols && y < rows).then_some(Point { x, y })
+        })
+}
+
+fn count_neighbors(grid: &Grid2D<bool>, point: Point) -> u8 {
+    neighbors(grid, point).filter(|&neighbor| grid[neighbor]).count() as u8
+}
+
+fn all_points(grid: &Grid2D<bool>) -> impl Iterator<Item = Point> + use<> {
+    let cols = grid.cols();
+    (0..grid.rows()).flat_map(move |y| (0..cols).map(move |x| Point { x, y }))
+}
+
+pub struct Removal {
+    initial: Grid2D<bool>,
+    rounds: Vec<Vec<Point>>,
+}
+
+impl Removal {
+    pub fn total_removed(&self) -> usize {
+        self.rounds.iter().map(Vec::len).sum()
+    }
+
+    pub fn round_counts(&self) -> Vec<usize> {
+        self.rounds.iter().map(Vec::len).collect()
+    }
+
+    pub fn rounds_until_stable(&self) -> usize {
+        self.rounds.len()
+    }
+
+    // Grid state after the given number of rounds
+    pub fn snapshot(&self, rounds: usize) -> Grid2D<bool> {
+        let mut grid = self.initial.clone();
+        for &point in self.rounds[..rounds].iter().flatten() {
+            grid[point] = false;
+        }
+
+        grid
+    }
+
+    pub fn survivors(&self) -> Grid2D<bool> {
+        self.snapshot(self.rounds.len())
+    }
+}
+
+pub fn simulate_removal(grid: &Grid2D<bool>, threshold: u8) -> Removal {
+    let mut present = grid.clone();
+    let mut counts = Grid2D(vec![vec![0_u8; grid.cols()]; grid.rows()]);
+    let mut queued = Grid2D(vec![vec![false; grid.cols()]; grid.rows()]);
+
+    let mut current = Vec::new();
+    for point in all_points(grid).filter(|&point| grid[point]) {
+        counts[point] = count_neighbors(grid, point);
+        if counts[point] < threshold {
+            queued[point] = true;
+            current.push(point);
+        }
+    }
+
+    let mut rounds = Vec::new();
+    while !current.is_empty() {
+        // Remove the whole round before updating counts so that rolls removed in the same round
+        // don't affect each other
+        for &point in &current {
+            present[point] = false;
+        }
+
+        let mut next = Vec::new();
+        for &point in &current {
+            for neighbor in neighbors(grid, point) {
+                if !present[neighbor] {
+                    continue;
+                }
+
+                counts[neighbor] -= 1;
+                if counts[neighbor] < threshold && !queued[neighbor] {
+                    queued[neighbor] = true;
+                    next.push(neighbor);
+                }
+            }
+        }
+
+        rounds.push(current);
+        current = next;
+    }
+
+    Removal { initial: grid.clone(), rounds }
+}
+
+pub fn render(grid: &Grid2D<bool>) -> String {
+    grid.0
+        .iter()
+        .map(|row| row.iter().map(|&occupied| if occupied { '@' } else { '.' }).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn part1(grid: &Grid2D<bool>, params: &Params) -> usize {
+    let threshold = params.get(&NEIGHBOR_THRESHOLD);
+
+    all_points(grid)
+        .filter(|&point| grid[point] && count_neighbors(grid, point) < threshold)
+        .count()
+}
+
+pub fn part2(grid: &Grid2D<bool>, params: &Params) -> usize {
+    simulate_removal(grid, params.get(&NEIGHBOR_THRESHOLD)).total_removed()
+}
+
+pub const SOLUTION: Solution = Solution {
+    day: 4,
+    params: &[&NEIGHBOR_THRESHOLD],
+    part1: |input, params| part1(&parse(input), params).to_string(),
+    part2: |input, params| part2(&parse(input), params).to_string(),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_test!(day4);
+
+    fn sample_input() -> &'static str {
+        include_str!("../examples/day4/sample.txt").trim_end()
+    }
+
+    #[test]
+    fn rounds() {
+        let removal = simulate_removal(&parse(sample_input()), NEIGHBOR_THRESHOLD.default);
+
+        assert_eq!(vec![13, 12, 7, 5, 2, 1, 1, 1, 1], removal.round_counts());
+        assert_eq!(9, removal.rounds_until_stable());
+        assert_eq!(sample_input(), render(&removal.snapshot(0)));
+
+        let survivors = "
+..........
+..........
+..........
+....@@....
+...@@@@...
+...@@@@@..
+...@.@.@@.
+...@@.@@@.
+...@@@@@..
+....@@@...
+"
+        .trim();
+        assert_eq!(survivors, render(&removal.survivors()));
+    }
+}
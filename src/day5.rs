@@ -0,0 +1,174 @@
+//! Day 5: Cafeteria
+//!
+//! <https://adventofcode.com/2025/day/5>
+
+use crate::Solution;
+use std::cmp;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl IdRange {
+    pub fn contains(self, value: u64) -> bool {
+        (self.start..=self.end).contains(&value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    pub fresh_ranges: Vec<IdRange>,
+    pub available_ids: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Inventory {
+    let mut lines = input.lines();
+
+    let fresh_ranges = lines
+        .by_ref()
+        .take_while(|line| !line.is_empty())
+        .map(|line| {
+            let (start, end) = line.split_once('-').expect("Split range");
+            let start: u64 = start.parse().expect("Parse start");
+            let end: u64 = end.parse().expect("Parse end");
+            IdRange { start, end }
+        })
+        .collect();
+
+    let available_ids = lines.map(|line| line.parse().expect("Parse ingredient ID")).collect();
+
+    Inventory { fresh_ranges, available_ids }
+}
+
+pub fn part1(inventory: &Inventory) -> usize {
+    inventory
+        .available_ids
+        .iter()
+        .filter(|&&id| inventory.fresh_ranges.iter().any(|&range| range.contains(id)))
+        .count()
+}
+
+pub fn part2(inventory: &Inventory) -> u64 {
+    let mut ranges = inventory.fresh_ranges.clone();
+
+    ranges.sort_by_key(|range| range.start);
+
+    let mut i = 0;
+    while i < ranges.len() - 1 {
+        if ranges[i + 1].start <= ranges[i].end {
+            ranges[i].end = cmp::max(ranges[i].end, ranges[i + 1].end);
+            ranges.remove(i + 1);
+            continue;
+        }
+
+        i += 1;
+    }
+
+    ranges.into_iter().map(|range| range.end - range.start + 1).sum()
+}
+
+pub const SOLUTION: Solution = Solution {
+    day: 5,
+    params: &[],
+    part1: |input, _| part1(&parse(input)).to_string(),
+    part2: |input, _| part2(&parse(input)).to_string(),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential;
+    use crate::rng::Rng;
+    use rustc_hash::FxHashSet;
+    use std::fmt::{self, Display};
+
+    crate::aoc_test!(day5);
+
+    #[derive(Debug, Clone)]
+    struct Case {
+        ranges: Vec<(u64, u64)>,
+        ids: Vec<u64>,
+    }
+
+    impl Display for Case {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for (start, end) in &self.ranges {
+                writeln!(f, "{start}-{end}")?;
+            }
+            writeln!(f)?;
+            for id in &self.ids {
+                writeln!(f, "{id}")?;
+            }
+            Ok(())
+        }
+    }
+
+    fn random_case(rng: &mut Rng) -> Case {
+        let ranges = (0..rng.range(1..=5))
+            .map(|_| {
+                let start = rng.range(0..=30);
+                (start, start + rng.range(0..=8))
+            })
+            .collect();
+        let ids = (0..rng.range(1..=5)).map(|_| rng.range(0..=40)).collect();
+        Case { ranges, ids }
+    }
+
+    // Candidates with a range or ID removed, or with a range narrowed or moved down
+    fn shrink_case(case: &Case) -> Vec<Case> {
+        let mut candidates = vec![];
+        for i in 0..case.ranges.len() {
+            let (start, end) = case.ranges[i];
+            let mut replace = |range: Option<(u64, u64)>| {
+                let mut ranges = case.ranges.clone();
+                match range {
+                    Some(range) => ranges[i] = range,
+                    None => _ = ranges.remove(i),
+                }
+                candidates.push(Case { ranges, ids: case.ids.clone() });
+            };
+
+            if case.ranges.len() > 1 {
+                replace(None);
+            }
+            if start < end {
+                replace(Some((start + 1, end)));
+                replace(Some((start, end - 1)));
+            }
+            if start > 0 {
+                replace(Some((start - 1, end - 1)));
+            }
+        }
+
+        for i in 0..case.ids.len() {
+            let mut ids = case.ids.clone();
+            ids.remove(i);
+            candidates.push(Case { ranges: case.ranges.clone(), ids });
+        }
+
+        candidates
+    }
+
+    // Enumerates every fresh ID instead of merging ranges
+    fn brute_force(case: &Case) -> (usize, u64) {
+        let fresh: FxHashSet<_> =
+            case.ranges.iter().flat_map(|&(start, end)| start..=end).collect();
+        let part_1 = case.ids.iter().filter(|id| fresh.contains(id)).count();
+        (part_1, fresh.len() as u64)
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        differential::check(
+            differential::cases(2000, random_case),
+            |case| {
+                let inventory = parse(&case.to_string());
+                (part1(&inventory), part2(&inventory))
+            },
+            brute_force,
+            shrink_case,
+        );
+    }
+}
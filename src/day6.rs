@@ -0,0 +1,312 @@
+//! Day 6: Trash Compactor
+//!
+//! <https://adventofcode.com/2025/day/6>
+//!
+//! The worksheet is split into problems at columns that are blank in every line, including the
+//! operator line. Lines may have ragged trailing whitespace, so every line is padded with spaces to
+//! the width of the longest line before looking for separators.
+//!
+//! Operators are looked up by symbol in an [`OperatorTable`], which has `+` and `*` from the puzzle
+//! along with `-`, `min`, `max` and `||` (concatenation), and can have more registered. Operands
+//! are folded left to right using checked signed 128-bit arithmetic, so that subtraction can go
+//! below zero.
+
+use crate::Grid2D;
+use crate::Solution;
+use rustc_hash::FxHashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidCharacter { c: char, line: usize, column: usize },
+    MissingOperator { column: usize },
+    UnknownOperator { symbol: String, column: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter { c, line, column } => {
+                write!(f, "Invalid character '{c}' at line {line}, column {column}")
+            }
+            Self::MissingOperator { column } => {
+                write!(f, "Problem starting at column {column} has no operator")
+            }
+            Self::UnknownOperator { symbol, column } => {
+                write!(f, "Unknown operator '{symbol}' at column {column}")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    NoOperands,
+    OperandOverflow,
+    Overflow { symbol: String },
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoOperands => write!(f, "Problem has no operands"),
+            Self::OperandOverflow => write!(f, "Operand does not fit in 128 bits"),
+            Self::Overflow { symbol } => write!(f, "Operator '{symbol}' overflowed"),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+/// Combines the running result with the next operand, or returns `None` on overflow.
+pub type Fold = fn(i128, i128) -> Option<i128>;
+
+#[derive(Debug, Clone)]
+pub struct Operator {
+    symbol: String,
+    fold: Fold,
+}
+
+impl Operator {
+    pub fn apply(&self, operands: &[i128]) -> Result<i128, EvalError> {
+        let (&first, rest) = operands.split_first().ok_or(EvalError::NoOperands)?;
+        rest.iter()
+            .try_fold(first, |acc, &operand| (self.fold)(acc, operand))
+            .ok_or_else(|| EvalError::Overflow { symbol: self.symbol.clone() })
+    }
+}
+
+// Appends the digits of `b`, which is never negative since it's an operand, to those of `a`
+fn concat(a: i128, b: i128) -> Option<i128> {
+    let shift = 10_i128.checked_pow(b.checked_ilog10().unwrap_or(0) + 1)?;
+    let shifted = a.checked_mul(shift)?;
+    if a < 0 { shifted.checked_sub(b) } else { shifted.checked_add(b) }
+}
+
+#[derive(Debug, Clone)]
+pub struct OperatorTable {
+    operators: FxHashMap<String, Operator>,
+}
+
+impl OperatorTable {
+    pub fn empty() -> Self {
+        Self { operators: FxHashMap::default() }
+    }
+
+    /// Adds an operator, replacing any existing operator with the same symbol.
+    pub fn register(&mut self, symbol: &str, fold: Fold) {
+        self.operators.insert(symbol.into(), Operator { symbol: symbol.into(), fold });
+    }
+
+    pub fn get(&self, symbol: &str) -> Option<&Operator> {
+        self.operators.get(symbol)
+    }
+}
+
+impl Default for OperatorTable {
+    fn default() -> Self {
+        let mut table = Self::empty();
+        table.register("+", i128::checked_add);
+        table.register("*", i128::checked_mul);
+        table.register("-", i128::checked_sub);
+        table.register("min", |a, b| Some(a.min(b)));
+        table.register("max", |a, b| Some(a.max(b)));
+        table.register("||", concat);
+        table
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Problem {
+    operator: Operator,
+    /// The operand lines of this problem's block, padded with spaces to the block width
+    cells: Grid2D<char>,
+}
+
+fn read_number(digits: impl Iterator<Item = char>) -> Result<Option<i128>, EvalError> {
+    digits
+        .filter_map(|c| c.to_digit(10))
+        .try_fold(None, |acc, digit| {
+            let value = acc.unwrap_or(0_i128).checked_mul(10)?.checked_add(digit.into())?;
+            Some(Some(value))
+        })
+        .ok_or(EvalError::OperandOverflow)
+}
+
+impl Problem {
+    /// Operands read left-to-right along each row, skipping rows that are blank in this block.
+    pub fn row_operands(&self) -> Result<Vec<i128>, EvalError> {
+        self.cells.0.iter().filter_map(|row| read_number(row.iter().copied()).transpose()).collect()
+    }
+
+    /// Operands read top-to-bottom down each column, starting from the rightmost column.
+    pub fn column_operands(&self) -> Result<Vec<i128>, EvalError> {
+        (0..self.cells.cols())
+            .rev()
+            .filter_map(|col| read_number(self.cells.0.iter().map(|row| row[col])).transpose())
+            .collect()
+    }
+
+    pub fn evaluate(
+        &self,
+        operands: impl Fn(&Self) -> Result<Vec<i128>, EvalError>,
+    ) -> Result<i128, EvalError> {
+        self.operator.apply(&operands(self)?)
+    }
+}
+
+/// Splits the worksheet into problems. Line and column numbers in errors are 1-based.
+pub fn parse_worksheet(input: &str, operators: &OperatorTable) -> Result<Vec<Problem>, ParseError> {
+    let mut lines: Vec<Vec<char>> =
+        input.lines().map(|line| line.trim_end().chars().collect()).collect();
+    while lines.last().is_some_and(Vec::is_empty) {
+        lines.pop();
+    }
+
+    let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+    for line in &mut lines {
+        line.resize(width, ' ');
+    }
+
+    let Some((operator_line, operand_lines)) = lines.split_last() else {
+        return Ok(vec![]);
+    };
+
+    for (i, line) in operand_lines.iter().enumerate() {
+        if let Some(column) = line.iter().position(|&c| c != ' ' && !c.is_ascii_digit()) {
+            return Err(ParseError::InvalidCharacter {
+                c: line[column],
+                line: i + 1,
+                column: column + 1,
+            });
+        }
+    }
+
+    let is_separator = |col: usize| lines.iter().all(|line| line[col] == ' ');
+
+    let mut problems = Vec::new();
+    let mut col = 0;
+    while col < width {
+        if is_separator(col) {
+            col += 1;
+            continue;
+        }
+
+        let start = col;
+        while col < width && !is_separator(col) {
+            col += 1;
+        }
+
+        let block = &operator_line[start..col];
+        let Some(offset) = block.iter().position(|&c| c != ' ') else {
+            return Err(ParseError::MissingOperator { column: start + 1 });
+        };
+        let symbol: String = block.iter().collect();
+        let symbol = symbol.trim();
+        let operator = operators.get(symbol).cloned().ok_or_else(|| {
+            ParseError::UnknownOperator { symbol: symbol.into(), column: start + offset + 1 }
+        })?;
+
+        let cells = Grid2D(operand_lines.iter().map(|line| line[start..col].to_vec()).collect());
+        problems.push(Problem { operator, cells });
+    }
+
+    Ok(problems)
+}
+
+/// Parses the worksheet using the default operators.
+pub fn parse(input: &str) -> Vec<Problem> {
+    parse_worksheet(input, &OperatorTable::default()).expect("Failed to parse input")
+}
+
+fn solve(
+    problems: &[Problem],
+    operands: impl Fn(&Problem) -> Result<Vec<i128>, EvalError>,
+) -> i128 {
+    problems
+        .iter()
+        .map(|problem| problem.evaluate(&operands).expect("Failed to evaluate problem"))
+        .try_fold(0, i128::checked_add)
+        .expect("Sum of all problems does not fit in 128 bits")
+}
+
+pub fn part1(problems: &[Problem]) -> i128 {
+    solve(problems, Problem::row_operands)
+}
+
+pub fn part2(problems: &[Problem]) -> i128 {
+    solve(problems, Problem::column_operands)
+}
+
+pub const SOLUTION: Solution = Solution {
+    day: 6,
+    params: &[],
+    part1: |input, _| part1(&parse(input)).to_string(),
+    part2: |input, _| part2(&parse(input)).to_string(),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_test!(day6);
+
+    #[test]
+    fn ragged_worksheet() {
+        // Trailing whitespace is trimmed from the first line and extended on the second, and the
+        // last problem's operator sits to the right of its operands
+        let input = "12 7\n 3 81   \n+   *\n";
+
+        let problems = parse_worksheet(input, &OperatorTable::default()).unwrap();
+        assert_eq!(2, problems.len());
+        assert_eq!(Ok(vec![12, 3]), problems[0].row_operands());
+        assert_eq!(Ok(vec![23, 1]), problems[0].column_operands());
+        assert_eq!("*", problems[1].operator.symbol);
+        assert_eq!(Ok(vec![7, 81]), problems[1].row_operands());
+        assert_eq!(Ok(vec![1, 78]), problems[1].column_operands());
+    }
+
+    #[test]
+    fn extended_operators() {
+        let input = "90 4   12 7 \n15 9   34 70\n-  min || ||";
+        let results: Vec<_> = parse_worksheet(input, &OperatorTable::default())
+            .unwrap()
+            .iter()
+            .map(|problem| problem.evaluate(Problem::row_operands))
+            .collect();
+        assert_eq!(vec![Ok(75), Ok(4), Ok(1234), Ok(770)], results);
+
+        let mut operators = OperatorTable::default();
+        operators.register("%", i128::checked_rem);
+        let problems = parse_worksheet("17  20\n5   15\n%   -", &operators).unwrap();
+        assert_eq!(Ok(2), problems[0].evaluate(Problem::row_operands));
+        assert_eq!(Ok(-16), problems[1].evaluate(Problem::column_operands));
+        assert_eq!(Some(-165), concat(-16, 5));
+
+        let problems = parse_worksheet(&format!("{}\n2\n*", i128::MAX), &operators).unwrap();
+        assert_eq!(
+            Err(EvalError::Overflow { symbol: "*".into() }),
+            problems[0].evaluate(Problem::row_operands)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let operators = OperatorTable::default();
+        assert_eq!(
+            Err(ParseError::UnknownOperator { symbol: "/".into(), column: 6 }),
+            parse_worksheet("1 2  3\n4 5  6\n+ *  /", &operators).map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::InvalidCharacter { c: 'x', line: 2, column: 3 }),
+            parse_worksheet("1 2\n4 x\n+ *", &operators).map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::MissingOperator { column: 3 }),
+            parse_worksheet("1 2\n4 5\n+", &operators).map(|_| ())
+        );
+    }
+}
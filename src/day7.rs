@@ -0,0 +1,743 @@
+//! Day 7: Laboratories
+//!
+//! <https://adventofcode.com/2025/day/7>
+//!
+//! Beams travel through the manifold one cell at a time in any of the four directions, and each
+//! [`Element`] decides where a beam goes after reaching it. Besides the puzzle's splitters (`^`),
+//! manifolds can contain mirrors (`/` and `\`), absorbers (`#`) and one-sided splitters (`<` and
+//! `>`). Manifolds with only splitters are counted with a sweep up from the bottom row, since
+//! every beam travels downwards; otherwise timelines are counted over the graph of (cell,
+//! direction) states. Either way, counting fails if a beam can loop forever.
+//!
+//! Beams leaving the top or bottom of the manifold exit. Beams leaving the left or right side are
+//! handled by an [`EdgePolicy`], which can be set with the `edges` parameter (`drop`, `wrap` or
+//! `exit`, defaulting to `exit`). The puzzle never places splitters on the edges, so its
+//! answers don't depend on the policy.
+//!
+//! A [`Trace`] of the beams over the manifold can be exported in several formats; see
+//! [`export_trace`].
+
+use crate::Solution;
+use crate::params::Param;
+use crate::{Grid2D, Point2D};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{self, Display, Write};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Up | Self::Down)
+    }
+
+    pub fn perpendicular(self) -> [Self; 2] {
+        if self.is_vertical() { [Self::Left, Self::Right] } else { [Self::Up, Self::Down] }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Element {
+    Empty,
+    /// Moves the beam one cell to each side, where both beams continue in the original direction
+    Splitter,
+    /// Like a splitter, but only moves the beam to the given side. Beams travelling towards or away
+    /// from that side pass straight through
+    OneSidedSplitter(Direction),
+    /// `/`
+    ForwardMirror,
+    /// `\`
+    BackMirror,
+    Absorber,
+}
+
+impl Element {
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Empty),
+            '^' => Some(Self::Splitter),
+            '<' => Some(Self::OneSidedSplitter(Direction::Left)),
+            '>' => Some(Self::OneSidedSplitter(Direction::Right)),
+            '/' => Some(Self::ForwardMirror),
+            '\\' => Some(Self::BackMirror),
+            '#' => Some(Self::Absorber),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::Splitter => '^',
+            Self::OneSidedSplitter(Direction::Left) => '<',
+            Self::OneSidedSplitter(Direction::Right) => '>',
+            Self::OneSidedSplitter(Direction::Up) => 'A',
+            Self::OneSidedSplitter(Direction::Down) => 'V',
+            Self::ForwardMirror => '/',
+            Self::BackMirror => '\\',
+            Self::Absorber => '#',
+        }
+    }
+
+    pub fn is_splitter(self) -> bool {
+        matches!(self, Self::Splitter | Self::OneSidedSplitter(_))
+    }
+
+    /// Where a beam travelling in the given direction goes after reaching this element, as pairs of
+    /// the direction to move one cell in and the direction to travel in afterwards.
+    pub fn deflect(self, direction: Direction) -> Vec<(Direction, Direction)> {
+        use Direction::{Down, Left, Right, Up};
+
+        match self {
+            Self::Empty => vec![(direction, direction)],
+            Self::Splitter => direction.perpendicular().map(|side| (side, direction)).to_vec(),
+            Self::OneSidedSplitter(side) => {
+                if side.is_vertical() == direction.is_vertical() {
+                    vec![(direction, direction)]
+                } else {
+                    vec![(side, direction)]
+                }
+            }
+            Self::ForwardMirror => {
+                let turned = match direction {
+                    Up => Right,
+                    Right => Up,
+                    Down => Left,
+                    Left => Down,
+                };
+                vec![(turned, turned)]
+            }
+            Self::BackMirror => {
+                let turned = match direction {
+                    Up => Left,
+                    Left => Up,
+                    Down => Right,
+                    Right => Down,
+                };
+                vec![(turned, turned)]
+            }
+            Self::Absorber => vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Beam {
+    pub point: Point2D<usize>,
+    pub direction: Direction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Move {
+    Beam(Beam),
+    Dropped,
+    Exited,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgePolicy {
+    /// The beam is lost
+    Drop,
+    /// The beam re-enters on the opposite side
+    Wrap,
+    /// The beam leaves the manifold, which counts as a completed timeline
+    Exit,
+}
+
+impl FromStr for EdgePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "drop" => Ok(Self::Drop),
+            "wrap" => Ok(Self::Wrap),
+            "exit" => Ok(Self::Exit),
+            _ => Err(format!("Unknown edge policy '{s}', expected 'drop', 'wrap' or 'exit'")),
+        }
+    }
+}
+
+pub const EDGES: Param<EdgePolicy> = Param::new("edges", EdgePolicy::Exit);
+
+impl EdgePolicy {
+    /// Moves one cell from the given point in the given step direction.
+    fn step<T>(self, grid: &Grid2D<T>, point: Point2D<usize>, step: Direction) -> Move {
+        let Point2D { x, y } = point;
+        let moved = match step {
+            Direction::Up => y.checked_sub(1).map(|y| Point2D { x, y }),
+            Direction::Down => Some(Point2D { x, y: y + 1 }).filter(|p| p.y < grid.rows()),
+            Direction::Left => x.checked_sub(1).map(|x| Point2D { x, y }),
+            Direction::Right => Some(Point2D { x: x + 1, y }).filter(|p| p.x < grid.cols()),
+        };
+
+        match (moved, step.is_vertical(), self) {
+            (Some(point), _, _) => Move::Beam(Beam { point, direction: step }),
+            (None, true, _) | (None, false, Self::Exit) => Move::Exited,
+            (None, false, Self::Drop) => Move::Dropped,
+            (None, false, Self::Wrap) => {
+                let x = if step == Direction::Left { grid.cols() - 1 } else { 0 };
+                Move::Beam(Beam { point: Point2D { x, y }, direction: step })
+            }
+        }
+    }
+}
+
+fn successors(grid: &Grid2D<Element>, beam: Beam, policy: EdgePolicy) -> Vec<Move> {
+    grid[beam.point]
+        .deflect(beam.direction)
+        .into_iter()
+        .map(|(step, direction)| match policy.step(grid, beam.point, step) {
+            Move::Beam(next) => Move::Beam(Beam { direction, ..next }),
+            other => other,
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelineError {
+    Overflow,
+    Loop(Beam),
+}
+
+impl Display for TimelineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "Timeline count does not fit in 128 bits"),
+            Self::Loop(Beam { point, direction }) => write!(
+                f,
+                "Beam loops forever through ({}, {}) moving {direction:?}",
+                point.x, point.y
+            ),
+        }
+    }
+}
+
+impl Error for TimelineError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manifold {
+    pub grid: Grid2D<Element>,
+    pub start: Point2D<usize>,
+}
+
+pub fn parse(input: &str) -> Manifold {
+    let mut start: Option<Point2D<usize>> = None;
+
+    let grid: Vec<Vec<_>> = input
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(col, c)| match c {
+                    'S' => {
+                        start = Some(Point2D { x: col, y: row });
+                        Element::Empty
+                    }
+                    _ => Element::from_char(c).unwrap_or_else(|| panic!("Invalid character {c}")),
+                })
+                .collect()
+        })
+        .collect();
+
+    Manifold { grid: Grid2D(grid), start: start.expect("No start in input") }
+}
+
+fn start_beam(start: Point2D<usize>) -> Beam {
+    Beam { point: start, direction: Direction::Down }
+}
+
+/// Marks the directions of every beam passing through each cell, including the elements that
+/// beams reach.
+fn light_beams(grid: &Grid2D<Element>, start: Point2D<usize>, policy: EdgePolicy) -> Grid2D<u8> {
+    let mut beams = Grid2D(vec![vec![0_u8; grid.cols()]; grid.rows()]);
+    let start = start_beam(start);
+    beams[start.point] |= start.direction.bit();
+
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+
+    while let Some(beam) = queue.pop_front() {
+        for next in successors(grid, beam, policy) {
+            if let Move::Beam(next) = next
+                && beams[next.point] & next.direction.bit() == 0
+            {
+                beams[next.point] |= next.direction.bit();
+                queue.push_back(next);
+            }
+        }
+    }
+
+    beams
+}
+
+fn all_points<T>(grid: &Grid2D<T>) -> impl Iterator<Item = Point2D<usize>> + use<T> {
+    let cols = grid.cols();
+    (0..grid.rows()).flat_map(move |y| (0..cols).map(move |x| Point2D { x, y }))
+}
+
+pub fn part1(manifold: &Manifold, policy: EdgePolicy) -> usize {
+    let Manifold { grid, start } = manifold;
+    let beams = light_beams(grid, *start, policy);
+
+    all_points(grid).filter(|&point| grid[point].is_splitter() && beams[point] != 0).count()
+}
+
+pub fn part2(manifold: &Manifold, policy: EdgePolicy) -> u128 {
+    let timelines =
+        count_timelines(&manifold.grid, manifold.start, policy).expect("Failed to count timelines");
+    timelines.from_start
+}
+
+#[derive(Debug, Clone)]
+pub struct Timelines {
+    pub from_start: u128,
+    /// Number of timelines leaving each cell, summed over the directions of the beams passing
+    /// through it, for every cell that a beam reaches
+    pub per_cell: Grid2D<Option<u128>>,
+}
+
+/// Counts the timelines leaving every cell that a beam reaches from the start, failing if a beam
+/// can loop forever. A beam exiting the manifold is one timeline.
+pub fn count_timelines(
+    grid: &Grid2D<Element>,
+    start: Point2D<usize>,
+    policy: EdgePolicy,
+) -> Result<Timelines, TimelineError> {
+    if grid.0.iter().flatten().all(|&element| matches!(element, Element::Empty | Element::Splitter))
+    {
+        sweep_timelines(grid, start, policy)
+    } else {
+        search_timelines(grid, start, policy)
+    }
+}
+
+/// Counts the timelines of a manifold with only empty space and splitters, where every beam
+/// travels downwards, by sweeping upwards from the bottom row and carrying the counts for the row
+/// below. A splitter that moves a beam onto another splitter sends it back and forth between them,
+/// which is only an error if a beam reaches it.
+fn sweep_timelines(
+    grid: &Grid2D<Element>,
+    start: Point2D<usize>,
+    policy: EdgePolicy,
+) -> Result<Timelines, TimelineError> {
+    let mut counts = Grid2D(vec![vec![Ok(0); grid.cols()]; grid.rows()]);
+    let mut below: Vec<Result<u128, TimelineError>> = vec![Ok(1); grid.cols()];
+
+    for y in (0..grid.rows()).rev() {
+        for x in 0..grid.cols() {
+            let point = Point2D { x, y };
+            counts[point] = match grid[point] {
+                Element::Splitter => [Direction::Left, Direction::Right]
+                    .into_iter()
+                    .map(|side| match policy.step(grid, point, side) {
+                        Move::Beam(Beam { point: side, .. }) if grid[side].is_splitter() => {
+                            Err(TimelineError::Loop(start_beam(point)))
+                        }
+                        Move::Beam(Beam { point: side, .. }) => below[side.x],
+                        Move::Dropped => Ok(0),
+                        Move::Exited => Ok(1),
+                    })
+                    .try_fold(0_u128, |sum, timelines| {
+                        sum.checked_add(timelines?).ok_or(TimelineError::Overflow)
+                    }),
+                _ => below[x],
+            };
+        }
+        below.clone_from(&counts.0[y]);
+    }
+
+    let from_start = counts[start]?;
+    let beams = light_beams(grid, start, policy);
+    let mut per_cell = Grid2D(vec![vec![None; grid.cols()]; grid.rows()]);
+    for point in all_points(grid).filter(|&point| beams[point] != 0) {
+        per_cell[point] = Some(counts[point]?);
+    }
+
+    Ok(Timelines { from_start, per_cell })
+}
+
+/// Counts the timelines leaving every beam state reachable from the start, using an iterative
+/// depth-first search that fails if it finds a state that leads back to itself.
+fn search_timelines(
+    grid: &Grid2D<Element>,
+    start: Point2D<usize>,
+    policy: EdgePolicy,
+) -> Result<Timelines, TimelineError> {
+    struct Frame {
+        beam: Beam,
+        moves: Vec<Move>,
+        next_move: usize,
+        timelines: u128,
+    }
+
+    let index =
+        |beam: Beam| (beam.point.y * grid.cols() + beam.point.x) * 4 + beam.direction as usize;
+    let frame =
+        |beam| Frame { beam, moves: successors(grid, beam, policy), next_move: 0, timelines: 0 };
+
+    let mut counts: Vec<Option<u128>> = vec![None; grid.rows() * grid.cols() * 4];
+    let mut in_progress = vec![false; counts.len()];
+
+    let start = start_beam(start);
+    in_progress[index(start)] = true;
+    let mut stack = vec![frame(start)];
+
+    while let Some(top) = stack.last_mut() {
+        let Some(&next) = top.moves.get(top.next_move) else {
+            let done = stack.pop().unwrap();
+            in_progress[index(done.beam)] = false;
+            counts[index(done.beam)] = Some(done.timelines);
+            if let Some(parent) = stack.last_mut() {
+                parent.timelines =
+                    parent.timelines.checked_add(done.timelines).ok_or(TimelineError::Overflow)?;
+            }
+            continue;
+        };
+        top.next_move += 1;
+
+        let timelines = match next {
+            Move::Dropped => 0,
+            Move::Exited => 1,
+            Move::Beam(beam) => match counts[index(beam)] {
+                Some(timelines) => timelines,
+                None if in_progress[index(beam)] => return Err(TimelineError::Loop(beam)),
+                None => {
+                    in_progress[index(beam)] = true;
+                    stack.push(frame(beam));
+                    continue;
+                }
+            },
+        };
+        top.timelines = top.timelines.checked_add(timelines).ok_or(TimelineError::Overflow)?;
+    }
+
+    let mut per_cell = Grid2D(vec![vec![None; grid.cols()]; grid.rows()]);
+    for point in all_points(grid) {
+        for direction in [Direction::Up, Direction::Down, Direction::Left, Direction::Right] {
+            if let Some(timelines) = counts[index(Beam { point, direction })] {
+                let cell: &mut Option<u128> = &mut per_cell[point];
+                *cell =
+                    Some(cell.unwrap_or(0).checked_add(timelines).ok_or(TimelineError::Overflow)?);
+            }
+        }
+    }
+
+    Ok(Timelines { from_start: counts[index(start)].unwrap(), per_cell })
+}
+
+#[derive(Debug, Clone)]
+pub struct Trace {
+    /// Columns lit by a beam in each row, in increasing order
+    pub lit: Vec<Vec<usize>>,
+    /// Bitmask of the directions of the beams passing through each cell
+    pub beams: Grid2D<u8>,
+    /// Number of timelines leaving each cell, for every cell that a beam reaches
+    pub timelines: Grid2D<Option<u128>>,
+    pub unreached_splitters: Vec<Point2D<usize>>,
+}
+
+pub fn trace(
+    grid: &Grid2D<Element>,
+    start: Point2D<usize>,
+    policy: EdgePolicy,
+) -> Result<Trace, TimelineError> {
+    let beams = light_beams(grid, start, policy);
+    let lit =
+        beams.0.iter().map(|row| (0..row.len()).filter(|&col| row[col] != 0).collect()).collect();
+    let timelines = count_timelines(grid, start, policy)?.per_cell;
+
+    let unreached_splitters =
+        all_points(grid).filter(|&point| grid[point].is_splitter() && beams[point] == 0).collect();
+
+    Ok(Trace { lit, beams, timelines, unreached_splitters })
+}
+
+pub fn render_ascii(grid: &Grid2D<Element>, trace: &Trace) -> String {
+    let vertical = Direction::Up.bit() | Direction::Down.bit();
+    let horizontal = Direction::Left.bit() | Direction::Right.bit();
+
+    let mut rows: Vec<Vec<char>> =
+        grid.0.iter().map(|row| row.iter().map(|element| element.symbol()).collect()).collect();
+
+    for (y, cols) in trace.lit.iter().enumerate() {
+        for &x in cols {
+            if grid.0[y][x] != Element::Empty {
+                continue;
+            }
+
+            let beams = trace.beams.0[y][x];
+            rows[y][x] = match (beams & vertical != 0, beams & horizontal != 0) {
+                (true, true) => '+',
+                (false, true) => '-',
+                _ => '|',
+            };
+        }
+    }
+
+    for point in &trace.unreached_splitters {
+        rows[point.y][point.x] = 'x';
+    }
+
+    rows.into_iter().map(String::from_iter).collect::<Vec<_>>().join("\n")
+}
+
+pub fn render_counts(trace: &Trace) -> String {
+    let width = trace.max_timelines().to_string().len();
+
+    trace
+        .timelines
+        .0
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|count| match count {
+                    Some(count) => format!("{count:>width$}"),
+                    None => format!("{:>width$}", "."),
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Trace {
+    fn max_timelines(&self) -> u128 {
+        self.timelines.0.iter().flatten().flatten().copied().max().unwrap_or(1)
+    }
+}
+
+fn heatmap_color(
+    grid: &Grid2D<Element>,
+    trace: &Trace,
+    point: Point2D<usize>,
+    max: u128,
+) -> [u8; 3] {
+    match (grid[point], trace.timelines[point]) {
+        (Element::Empty, None) => [0, 0, 0],
+        (Element::Empty, Some(count)) => {
+            // Log scale from dark blue for a single timeline to yellow for the most timelines
+            let t = if max > 1 { (count as f64).ln() / (max as f64).ln() } else { 1.0 };
+            [(255.0 * t) as u8, (255.0 * t * t) as u8, (160.0 * (1.0 - t)) as u8 + 64]
+        }
+        (_, Some(_)) => [255, 255, 255],
+        (_, None) => [96, 96, 96],
+    }
+}
+
+/// Renders the timeline heatmap as a plain (ASCII) PPM image with one pixel per cell.
+pub fn render_ppm(grid: &Grid2D<Element>, trace: &Trace) -> String {
+    let max = trace.max_timelines();
+    let mut ppm = format!("P3\n{} {}\n255\n", grid.cols(), grid.rows());
+    for y in 0..grid.rows() {
+        let row: Vec<_> = (0..grid.cols())
+            .map(|x| {
+                let [r, g, b] = heatmap_color(grid, trace, Point2D { x, y }, max);
+                format!("{r} {g} {b}")
+            })
+            .collect();
+        writeln!(ppm, "{}", row.join(" ")).unwrap();
+    }
+
+    ppm
+}
+
+pub fn render_svg(grid: &Grid2D<Element>, trace: &Trace) -> String {
+    const CELL_SIZE: usize = 10;
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}">"#,
+        grid.cols() * CELL_SIZE,
+        grid.rows() * CELL_SIZE
+    )
+    .unwrap();
+
+    let max = trace.max_timelines();
+    for point in all_points(grid) {
+        let [r, g, b] = heatmap_color(grid, trace, point, max);
+        write!(
+            svg,
+            r#"  <rect x="{}" y="{}" width="{CELL_SIZE}" height="{CELL_SIZE}" fill="rgb({r},{g},{b})">"#,
+            point.x * CELL_SIZE,
+            point.y * CELL_SIZE
+        )
+        .unwrap();
+        if let Some(count) = trace.timelines[point] {
+            write!(svg, "<title>{count}</title>").unwrap();
+        }
+        writeln!(svg, "</rect>").unwrap();
+    }
+
+    writeln!(svg, "</svg>").unwrap();
+    svg
+}
+
+/// Renders a trace of the beams in one of these formats:
+/// - `ascii` draws beams as `|` or `-` (or `+` where beams cross), splitters that a beam hit as `^`
+///   and splitters that no beam reached as `x`
+/// - `counts` prints the number of timelines leaving each lit cell
+/// - `ppm` and `svg` draw those timeline counts as a heatmap on a log scale
+pub fn export_trace(
+    manifold: &Manifold,
+    policy: EdgePolicy,
+    format: &str,
+) -> Result<String, Box<dyn Error>> {
+    let Manifold { grid, start } = manifold;
+    let trace = trace(grid, *start, policy)?;
+
+    match format {
+        "ascii" => Ok(render_ascii(grid, &trace) + "\n"),
+        "counts" => Ok(render_counts(&trace) + "\n"),
+        "ppm" => Ok(render_ppm(grid, &trace)),
+        "svg" => Ok(render_svg(grid, &trace)),
+        _ => Err(format!(
+            "Unknown export format '{format}', expected 'ascii', 'counts', 'ppm' or 'svg'"
+        )
+        .into()),
+    }
+}
+
+pub const SOLUTION: Solution = Solution {
+    day: 7,
+    params: &[&EDGES],
+    part1: |input, params| part1(&parse(input), params.get(&EDGES)).to_string(),
+    part2: |input, params| part2(&parse(input), params.get(&EDGES)).to_string(),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_test!(day7);
+
+    fn sample_input() -> &'static str {
+        include_str!("../examples/day7/sample.txt")
+    }
+
+    #[test]
+    fn trace_sample() {
+        let Manifold { grid, start } = parse(sample_input());
+        let trace = trace(&grid, start, EdgePolicy::Exit).unwrap();
+
+        assert_eq!(vec![7], trace.lit[0]);
+        assert_eq!(vec![6, 7, 8], trace.lit[2]);
+        assert_eq!(Some(40), trace.timelines[start]);
+        assert_eq!(vec![Point2D { x: 9, y: 14 }], trace.unreached_splitters);
+
+        let expected = "
+.......|.......
+.......|.......
+......|^|......
+......|.|......
+.....|^|^|.....
+.....|.|.|.....
+....|^|^|^|....
+....|.|.|.|....
+...|^|^|||^|...
+...|.|.|||.|...
+..|^|^|||^|^|..
+..|.|.|||.|.|..
+.|^|||^||.||^|.
+.|.|||.||.||.|.
+|^|^|^|^|x|||^|
+|.|.|.|.|.|||.|
+";
+        assert_eq!(expected.trim(), render_ascii(&grid, &trace));
+    }
+
+    #[test]
+    fn adjacent_splitters() {
+        // Adjacent splitters are fine as long as no beam reaches them
+        let manifold = parse("..S......\n.........\n..^......\n.........\n.^.^..^^.\n.........");
+        assert_eq!(3, part1(&manifold, EdgePolicy::Exit));
+        assert_eq!(4, part2(&manifold, EdgePolicy::Exit));
+    }
+
+    #[test]
+    fn edge_policies() {
+        let Manifold { grid, start } = parse("S....\n^....\n.....\n....^\n.....");
+
+        for (policy, splitters_hit, timelines) in
+            [(EdgePolicy::Drop, 1, 1), (EdgePolicy::Wrap, 2, 3), (EdgePolicy::Exit, 1, 2)]
+        {
+            let beams = light_beams(&grid, start, policy);
+            let hit = all_points(&grid).filter(|&p| grid[p].is_splitter() && beams[p] != 0).count();
+            assert_eq!(splitters_hit, hit, "{policy:?}");
+            let counted = count_timelines(&grid, start, policy).map(|t| t.from_start);
+            assert_eq!(Ok(timelines), counted, "{policy:?}");
+        }
+
+        // Each splitter in a tall stack of offset pairs doubles the timelines
+        let mut rows = vec!["..S..".to_string()];
+        for _ in 0..130 {
+            rows.extend([".....".into(), "..^..".into(), ".....".into(), ".^.^.".into()]);
+        }
+        let Manifold { grid, start } = parse(&rows.join("\n"));
+        let counted = count_timelines(&grid, start, EdgePolicy::Exit).map(|t| t.from_start);
+        assert_eq!(Err(TimelineError::Overflow), counted);
+    }
+
+    #[test]
+    fn sweep_matches_search() {
+        let inputs = [
+            sample_input(),
+            "..S......\n.........\n..^......\n.........\n.^.^..^^.\n.........",
+            "S....\n^....\n.....\n....^\n.....",
+            "...S...\n...^...\n..^^...\n.......",
+            "S\n^\n.",
+        ];
+        for input in inputs {
+            let Manifold { grid, start } = parse(input);
+            for policy in [EdgePolicy::Drop, EdgePolicy::Wrap, EdgePolicy::Exit] {
+                let swept = sweep_timelines(&grid, start, policy);
+                let searched = search_timelines(&grid, start, policy);
+                assert_eq!(
+                    searched.as_ref().map(|t| (t.from_start, &t.per_cell)),
+                    swept.as_ref().map(|t| (t.from_start, &t.per_cell)),
+                    "{input} {policy:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn mirrors_and_loops() {
+        // The beam turns right at the mirror and is split upwards and downwards. The lower beam
+        // is turned into the absorber, and the upper beam leaves the right side of the manifold
+        let input = ".S...\n.\\.^#\n..../";
+        let Manifold { grid, start } = parse(input);
+
+        let traced = trace(&grid, start, EdgePolicy::Exit).unwrap();
+        assert_eq!(Some(1), traced.timelines[start]);
+        assert_eq!(".|.--\n.\\-^#\n...-/", render_ascii(&grid, &traced));
+
+        let counted = count_timelines(&grid, start, EdgePolicy::Drop).map(|t| t.from_start);
+        assert_eq!(Ok(0), counted);
+
+        // Wrapping sends the upper beam back through the start cell and into its own path
+        let looped = Beam { point: Point2D { x: 3, y: 0 }, direction: Direction::Right };
+        let counted = count_timelines(&grid, start, EdgePolicy::Wrap).map(|t| t.from_start);
+        assert_eq!(Err(TimelineError::Loop(looped)), counted);
+
+        // One-sided splitters only move beams to one side, and let parallel beams pass through
+        let Manifold { grid, start } = parse("S.<..\n>..#.\n.....");
+        let traced = trace(&grid, start, EdgePolicy::Exit).unwrap();
+        assert_eq!(vec![vec![0], vec![0, 1], vec![1]], traced.lit);
+        assert_eq!(Some(1), traced.timelines[start]);
+        assert_eq!(vec![Point2D { x: 2, y: 0 }], traced.unreached_splitters);
+    }
+}
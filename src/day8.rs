@@ -0,0 +1,150 @@
+//! Day 8: Playground
+//!
+//! <https://adventofcode.com/2025/day/8>
+
+use crate::Point3D;
+use crate::Solution;
+use crate::kdtree::{KdTree, Neighbor};
+use crate::mst::{self, Edge};
+use crate::params::{Param, Params};
+use crate::union_find::UnionFind;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use winnow::ascii::{digit1, newline};
+use winnow::combinator::{opt, separated, terminated};
+use winnow::prelude::*;
+
+type Point = Point3D<i64>;
+
+pub const CONNECTIONS: Param<usize> = Param::new("connections", 1000);
+
+fn parse_i64(input: &mut &str) -> winnow::Result<i64> {
+    digit1.parse_to().parse_next(input)
+}
+
+fn parse_point(input: &mut &str) -> winnow::Result<Point> {
+    let (x, _, y, _, z) = (parse_i64, ',', parse_i64, ',', parse_i64).parse_next(input)?;
+    Ok(Point { x, y, z })
+}
+
+fn parse_points(input: &mut &str) -> winnow::Result<Vec<Point>> {
+    terminated(separated(1.., parse_point, newline), opt(newline)).parse_next(input)
+}
+
+pub fn parse(input: &str) -> Vec<Point> {
+    parse_points.parse(input).expect("Failed to parse input")
+}
+
+const INITIAL_NEIGHBORS_PER_QUERY: usize = 4;
+
+// Lazily yields the neighbors of a single point in (distance, index) order, only considering
+// neighbors with a higher index so that each pair is produced exactly once
+struct NeighborCursor {
+    idx: usize,
+    buffer: Vec<Neighbor>, // Reversed so that the next neighbor is at the end
+    last: Option<Neighbor>,
+    k: usize,
+    exhausted: bool,
+}
+
+impl NeighborCursor {
+    fn new(idx: usize) -> Self {
+        Self {
+            idx,
+            buffer: Vec::new(),
+            last: None,
+            k: INITIAL_NEIGHBORS_PER_QUERY,
+            exhausted: false,
+        }
+    }
+
+    fn next(&mut self, tree: &KdTree) -> Option<Neighbor> {
+        if self.buffer.is_empty() && !self.exhausted {
+            let idx = self.idx;
+            let last = self.last;
+            self.buffer = tree.k_nearest_filtered(tree.points()[idx], self.k, |neighbor| {
+                neighbor.idx > idx && last.is_none_or(|last| neighbor > last)
+            });
+
+            self.exhausted = self.buffer.len() < self.k;
+            self.buffer.reverse();
+            self.k *= 2;
+        }
+
+        let neighbor = self.buffer.pop()?;
+        self.last = Some(neighbor);
+        Some(neighbor)
+    }
+}
+
+// Yields every pair of points in increasing distance order, breaking ties the same way as a stable
+// sort over all (i, j) pairs with i < j would
+struct ConnectionsByDistance {
+    tree: KdTree,
+    cursors: Vec<NeighborCursor>,
+    heap: BinaryHeap<Reverse<Edge<i64>>>,
+}
+
+impl Iterator for ConnectionsByDistance {
+    type Item = Edge<i64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse(connection) = self.heap.pop()?;
+
+        if let Some(neighbor) = self.cursors[connection.a].next(&self.tree) {
+            self.heap.push(Reverse(neighbor_edge(connection.a, neighbor)));
+        }
+
+        Some(connection)
+    }
+}
+
+fn neighbor_edge(idx: usize, neighbor: Neighbor) -> Edge<i64> {
+    Edge { weight: neighbor.distance_squared, a: idx, b: neighbor.idx }
+}
+
+fn connections_by_distance(points: &[Point]) -> ConnectionsByDistance {
+    let tree = KdTree::new(points);
+    let mut cursors: Vec<_> = (0..points.len()).map(NeighborCursor::new).collect();
+
+    let mut heap = BinaryHeap::with_capacity(points.len());
+    for (i, cursor) in cursors.iter_mut().enumerate() {
+        if let Some(neighbor) = cursor.next(&tree) {
+            heap.push(Reverse(neighbor_edge(i, neighbor)));
+        }
+    }
+
+    ConnectionsByDistance { tree, cursors, heap }
+}
+
+pub fn part1(points: &[Point], params: &Params) -> usize {
+    let mut circuits = UnionFind::new(points.len());
+    for connection in connections_by_distance(points).take(params.get(&CONNECTIONS)) {
+        circuits.union(connection.a, connection.b);
+    }
+
+    let mut sizes = circuits.component_sizes();
+    sizes.sort_by_key(|&size| Reverse(size));
+    sizes[..3].iter().copied().product()
+}
+
+// Part 2 is Kruskal's algorithm, with the answer coming from the edge that connects every circuit
+pub fn part2(points: &[Point]) -> i64 {
+    let tree = mst::kruskal(points.len(), connections_by_distance(points));
+    let connection =
+        tree.completing_edge().expect("Circuit never fully connected; should be impossible");
+
+    points[connection.a].x * points[connection.b].x
+}
+
+pub const SOLUTION: Solution = Solution {
+    day: 8,
+    params: &[&CONNECTIONS],
+    part1: |input, params| part1(&parse(input), params).to_string(),
+    part2: |input, _| part2(&parse(input)).to_string(),
+};
+
+#[cfg(test)]
+mod tests {
+    crate::aoc_test!(day8);
+}
@@ -0,0 +1,267 @@
+//! Day 9: Movie Theater
+//!
+//! <https://adventofcode.com/2025/day/9>
+//!
+//! Part 1 is trivial, Part 2 very much not so.
+//!
+//! Part 2 treats the red tiles as the vertices of a rectilinear polygon and searches for the
+//! largest rectangle with red tiles at two opposite corners that fits entirely inside it. See the
+//! `inscribed_rectangle` library module for how the search works.
+
+use crate::Point2D;
+use crate::Solution;
+use crate::inscribed_rectangle::{self, CornerConstraint};
+use crate::polygon::RectilinearPolygon;
+use std::cmp;
+use winnow::ascii::{digit1, newline};
+use winnow::combinator::{opt, separated, separated_pair, terminated};
+use winnow::prelude::*;
+
+type Point = Point2D<i64>;
+
+fn parse_i64(input: &mut &str) -> winnow::Result<i64> {
+    digit1.parse_to().parse_next(input)
+}
+
+fn parse_point(input: &mut &str) -> winnow::Result<Point> {
+    let (x, y) = separated_pair(parse_i64, ',', parse_i64).parse_next(input)?;
+    Ok(Point { x, y })
+}
+
+fn parse_points(input: &mut &str) -> winnow::Result<Vec<Point>> {
+    terminated(separated(1.., parse_point, newline), opt(newline)).parse_next(input)
+}
+
+pub fn parse(input: &str) -> Vec<Point> {
+    parse_points.parse(input).expect("Failed to parse input")
+}
+
+fn rectangle_area(point: Point, other_point: Point) -> i64 {
+    ((point.x - other_point.x).abs() + 1) * ((point.y - other_point.y).abs() + 1)
+}
+
+pub fn part1(points: &[Point]) -> i64 {
+    let mut max = 0;
+    for (i, &point) in points.iter().enumerate() {
+        for &other_point in &points[i + 1..] {
+            let area = rectangle_area(point, other_point);
+            max = cmp::max(max, area);
+        }
+    }
+
+    max
+}
+
+pub fn part2(points: &[Point]) -> i64 {
+    let polygon =
+        RectilinearPolygon::new(points.to_vec()).expect("Red tiles do not form a valid loop");
+
+    let rectangle = inscribed_rectangle::largest_rectangle(&polygon, CornerConstraint::Vertices)
+        .expect("Vertex-constrained search always finds a rectangle");
+    rectangle.tile_area()
+}
+
+pub const SOLUTION: Solution = Solution {
+    day: 9,
+    params: &[],
+    part1: |input, _| part1(&parse(input)).to_string(),
+    part2: |input, _| part2(&parse(input)).to_string(),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential;
+    use crate::rng::Rng;
+    use rustc_hash::FxHashMap;
+    use std::fmt::{self, Display};
+
+    crate::aoc_test!(day9);
+
+    #[derive(Debug, Clone)]
+    struct Case {
+        vertices: Vec<Point>,
+    }
+
+    impl Display for Case {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for Point { x, y } in &self.vertices {
+                writeln!(f, "{x},{y}")?;
+            }
+            Ok(())
+        }
+    }
+
+    const CELLS: i64 = 6;
+
+    // Traces the outline of a set of grid cells, with the interior on the left. Returns None if
+    // the cells have holes or only touch diagonally somewhere, since then the outline is not a
+    // single simple loop
+    fn trace_outline(cells: &[(i64, i64)]) -> Option<Vec<(i64, i64)>> {
+        let mut next = FxHashMap::default();
+        for &(x, y) in cells {
+            let sides = [
+                ((0, -1), (x, y), (x + 1, y)),
+                ((1, 0), (x + 1, y), (x + 1, y + 1)),
+                ((0, 1), (x + 1, y + 1), (x, y + 1)),
+                ((-1, 0), (x, y + 1), (x, y)),
+            ];
+            for ((dx, dy), start, end) in sides {
+                if !cells.contains(&(x + dx, y + dy)) && next.insert(start, end).is_some() {
+                    return None;
+                }
+            }
+        }
+
+        let first = *next.keys().min().unwrap();
+        let mut outline = vec![first];
+        let mut point = next[&first];
+        while point != first {
+            outline.push(point);
+            point = next[&point];
+        }
+        if outline.len() != next.len() {
+            return None;
+        }
+
+        // Only keep the points where the outline turns
+        let len = outline.len();
+        let turns = (0..len)
+            .filter(|&i| {
+                let (prev, point, next) =
+                    (outline[(i + len - 1) % len], outline[i], outline[(i + 1) % len]);
+                (point.0 - prev.0, point.1 - prev.1) != (next.0 - point.0, next.1 - point.1)
+            })
+            .map(|i| outline[i])
+            .collect();
+        Some(turns)
+    }
+
+    // Grows a random polyomino and spaces its grid lines at least 2 tiles apart, so that the
+    // polygon's edges never run along adjacent tiles
+    fn random_case(rng: &mut Rng) -> Case {
+        let outline = loop {
+            let mut cells = vec![(rng.range(0..=5) as i64, rng.range(0..=5) as i64)];
+            let target = rng.range(1..=12) as usize;
+            while cells.len() < target {
+                let &(x, y) = rng.choose(&cells);
+                let &(dx, dy) = rng.choose(&[(1, 0), (-1, 0), (0, 1), (0, -1)]);
+                let cell = (x + dx, y + dy);
+                if (0..CELLS).contains(&cell.0)
+                    && (0..CELLS).contains(&cell.1)
+                    && !cells.contains(&cell)
+                {
+                    cells.push(cell);
+                }
+            }
+
+            if let Some(outline) = trace_outline(&cells) {
+                break outline;
+            }
+        };
+
+        let lines = |rng: &mut Rng| {
+            let mut coords = vec![rng.range(0..=3) as i64];
+            for _ in 0..CELLS {
+                coords.push(coords.last().unwrap() + rng.range(2..=4) as i64);
+            }
+            coords
+        };
+        let (xs, ys) = (lines(rng), lines(rng));
+
+        let mut vertices: Vec<_> = outline
+            .into_iter()
+            .map(|(x, y)| Point { x: xs[x as usize], y: ys[y as usize] })
+            .collect();
+        if rng.chance(1, 2) {
+            vertices.reverse();
+        }
+        Case { vertices }
+    }
+
+    // Candidates with every coordinate past some line moved one tile closer, as long as edges
+    // stay at least 2 tiles apart
+    fn shrink_case(case: &Case) -> Vec<Case> {
+        let mut candidates = vec![];
+        for axis in [0, 1] {
+            let coord = |point: &Point| if axis == 0 { point.x } else { point.y };
+            let mut coords: Vec<_> = case.vertices.iter().map(coord).collect();
+            coords.sort_unstable();
+            coords.dedup();
+
+            for (i, &line) in coords.iter().enumerate() {
+                if (i > 0 && line - coords[i - 1] <= 2) || (i == 0 && line == 0) {
+                    continue;
+                }
+
+                let vertices = case
+                    .vertices
+                    .iter()
+                    .map(|&point| match axis {
+                        0 if point.x >= line => Point { x: point.x - 1, ..point },
+                        1 if point.y >= line => Point { y: point.y - 1, ..point },
+                        _ => point,
+                    })
+                    .collect();
+                candidates.push(Case { vertices });
+            }
+        }
+
+        candidates
+    }
+
+    // Rasterizes the polygon, flood fills the outside from a padded border, and then checks every
+    // tile of every candidate rectangle
+    fn brute_force_part_2(case: &Case) -> i64 {
+        let width = case.vertices.iter().map(|point| point.x).max().unwrap() as usize + 3;
+        let height = case.vertices.iter().map(|point| point.y).max().unwrap() as usize + 3;
+        let tile = |point: Point| (point.x as usize + 1, point.y as usize + 1);
+
+        let mut boundary = vec![vec![false; width]; height];
+        for (i, &start) in case.vertices.iter().enumerate() {
+            let end = case.vertices[(i + 1) % case.vertices.len()];
+            let ((x0, y0), (x1, y1)) = (tile(start), tile(end));
+            for row in &mut boundary[cmp::min(y0, y1)..=cmp::max(y0, y1)] {
+                row[cmp::min(x0, x1)..=cmp::max(x0, x1)].fill(true);
+            }
+        }
+
+        let mut outside = vec![vec![false; width]; height];
+        let mut stack: Vec<(usize, usize)> = vec![(0, 0)];
+        outside[0][0] = true;
+        while let Some((x, y)) = stack.pop() {
+            let neighbors =
+                [(x + 1, y), (x.wrapping_sub(1), y), (x, y + 1), (x, y.wrapping_sub(1))];
+            for (nx, ny) in neighbors {
+                if nx < width && ny < height && !boundary[ny][nx] && !outside[ny][nx] {
+                    outside[ny][nx] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+
+        let mut max = 0;
+        for &a in &case.vertices {
+            for &b in &case.vertices {
+                let ((x0, y0), (x1, y1)) = (tile(a), tile(b));
+                let inside = (cmp::min(y0, y1)..=cmp::max(y0, y1))
+                    .all(|y| (cmp::min(x0, x1)..=cmp::max(x0, x1)).all(|x| !outside[y][x]));
+                if inside {
+                    max = cmp::max(max, rectangle_area(a, b));
+                }
+            }
+        }
+
+        max
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        differential::check(
+            differential::cases(2000, random_case),
+            |case| part2(&parse(&case.to_string())),
+            brute_force_part_2,
+            shrink_case,
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! Differential testing of optimized solutions against brute-force reference implementations
+//!
+//! Both implementations are run on many small randomly generated cases. When they disagree, the
+//! failing case is shrunk greedily: the shrinker proposes smaller variants of the case, and the
+//! first variant that still makes the implementations disagree replaces it, until no proposed
+//! variant fails. Shrinkers are responsible for only proposing valid cases.
+
+use crate::rng::Rng;
+use std::fmt::{self, Debug, Display};
+
+/// A case that the implementations disagree on, after shrinking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch<I, O> {
+    pub case: I,
+    pub expected: O,
+    pub actual: O,
+    pub original: I,
+}
+
+impl<I: Display, O: Debug> Display for Mismatch<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Expected {:?} but got {:?} for case:", self.expected, self.actual)?;
+        writeln!(f, "{}", self.case)?;
+        writeln!(f, "Shrunk from case:")?;
+        write!(f, "{}", self.original)
+    }
+}
+
+/// Generates `count` cases, each from its own RNG seeded with the case's index so that any case
+/// can be reproduced on its own.
+pub fn cases<I>(count: u64, mut generate: impl FnMut(&mut Rng) -> I) -> impl Iterator<Item = I> {
+    (0..count).map(move |seed| generate(&mut Rng::new(seed)))
+}
+
+/// Returns the first case that the implementations disagree on, shrunk as far as possible.
+pub fn find_mismatch<I, O>(
+    cases: impl IntoIterator<Item = I>,
+    solve: impl Fn(&I) -> O,
+    reference: impl Fn(&I) -> O,
+    shrink: impl Fn(&I) -> Vec<I>,
+) -> Option<Mismatch<I, O>>
+where
+    I: Clone,
+    O: PartialEq,
+{
+    let run = |case: &I| {
+        let (expected, actual) = (reference(case), solve(case));
+        (expected != actual).then_some((expected, actual))
+    };
+
+    let (original, (mut expected, mut actual)) =
+        cases.into_iter().find_map(|case| run(&case).map(|outputs| (case, outputs)))?;
+
+    let mut case = original.clone();
+    'shrink: loop {
+        for candidate in shrink(&case) {
+            if let Some(outputs) = run(&candidate) {
+                (case, (expected, actual)) = (candidate, outputs);
+                continue 'shrink;
+            }
+        }
+
+        return Some(Mismatch { case, expected, actual, original });
+    }
+}
+
+/// Panics with the shrunk case if the implementations disagree on any case.
+#[track_caller]
+pub fn check<I, O>(
+    cases: impl IntoIterator<Item = I>,
+    solve: impl Fn(&I) -> O,
+    reference: impl Fn(&I) -> O,
+    shrink: impl Fn(&I) -> Vec<I>,
+) where
+    I: Clone + Display,
+    O: PartialEq + Debug,
+{
+    if let Some(mismatch) = find_mismatch(cases, solve, reference, shrink) {
+        panic!("{mismatch}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Candidates with one element removed, or one element decremented
+    fn shrink_vec(values: &[u32]) -> Vec<Vec<u32>> {
+        let removed = (0..values.len()).map(|i| {
+            let mut values = values.to_vec();
+            values.remove(i);
+            values
+        });
+        let decremented = (0..values.len()).filter(|&i| values[i] > 0).map(|i| {
+            let mut values = values.to_vec();
+            values[i] -= 1;
+            values
+        });
+        removed.chain(decremented).collect()
+    }
+
+    fn random_vec(rng: &mut Rng) -> Vec<u32> {
+        (0..rng.range(0..=8)).map(|_| rng.range(0..=20) as u32).collect()
+    }
+
+    #[test]
+    fn shrinks_to_minimal_case() {
+        // Buggy sum that ignores the last element whenever there are at least 3 elements
+        let buggy_sum = |values: &Vec<u32>| match values.as_slice() {
+            [init @ .., _] if values.len() >= 3 => init.iter().sum(),
+            _ => values.iter().sum(),
+        };
+        let sum = |values: &Vec<u32>| values.iter().sum::<u32>();
+
+        let mismatch =
+            find_mismatch(cases(1000, random_vec), buggy_sum, sum, |values| shrink_vec(values))
+                .unwrap();
+        assert_eq!(vec![0, 0, 1], mismatch.case);
+        assert_eq!((1, 0), (mismatch.expected, mismatch.actual));
+        assert!(mismatch.original.len() >= 3);
+    }
+
+    #[test]
+    fn agreeing_implementations() {
+        let sum = |values: &Vec<u32>| values.iter().sum::<u32>();
+        let reversed = |values: &Vec<u32>| values.iter().rev().sum::<u32>();
+
+        assert_eq!(
+            None,
+            find_mismatch(cases(1000, random_vec), sum, reversed, |values| shrink_vec(values))
+        );
+    }
+}
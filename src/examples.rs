@@ -0,0 +1,157 @@
+//! Example inputs with known answers, which are run as tests
+//!
+//! Examples live in `examples/<day>/`, e.g. `examples/day8/sample.txt`. Each example input has a
+//! sidecar file with the same name and an `.answers` extension that holds the expected answers,
+//! one `name = value` pair per line:
+//!
+//! ```text
+//! part1 = 40
+//! part2 = 25272
+//! ```
+//!
+//! Only the parts with an expected answer are run, so an example can cover just one part. An
+//! `ignore = <reason>` line marks an example that the solution is known to get wrong. Parameter
+//! overrides go in the usual `.params` sidecar file, which the day's executable also picks up.
+//!
+//! The build script generates a test for every example input, and [`aoc_test!`](crate::aoc_test)
+//! includes the tests for a day, so adding an example only takes adding its files.
+
+use crate::Solution;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnswersError {
+    Malformed(String),
+    UnknownKey(String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed(line) => write!(f, "Expected 'name = value', got '{line}'"),
+            Self::UnknownKey(key) => {
+                write!(f, "Unknown key '{key}', expected 'part1', 'part2' or 'ignore'")
+            }
+        }
+    }
+}
+
+impl Error for AnswersError {}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    /// Why the example is ignored, if it is
+    pub ignore: Option<String>,
+}
+
+impl Answers {
+    /// Parses the contents of an answers file. Blank lines and lines starting with `#` are
+    /// ignored.
+    pub fn parse(contents: &str) -> Result<Self, AnswersError> {
+        let mut answers = Self::default();
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) =
+                line.split_once('=').ok_or_else(|| AnswersError::Malformed(line.into()))?;
+            let field = match key.trim() {
+                "part1" => &mut answers.part1,
+                "part2" => &mut answers.part2,
+                "ignore" => &mut answers.ignore,
+                key => return Err(AnswersError::UnknownKey(key.into())),
+            };
+            *field = Some(value.trim().into());
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields = [("part1", &self.part1), ("part2", &self.part2), ("ignore", &self.ignore)];
+        for (key, value) in fields {
+            if let Some(value) = value {
+                writeln!(f, "{key} = {value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs the solution on the example input at the given path and panics if any answer is wrong.
+#[track_caller]
+pub fn check(solution: &Solution, path: &Path) {
+    if let Err(err) = try_check(solution, path) {
+        panic!("{}: {err}", path.display());
+    }
+}
+
+fn try_check(solution: &Solution, path: &Path) -> Result<(), Box<dyn Error>> {
+    let input = fs::read_to_string(path)?;
+    let answers = Answers::parse(&fs::read_to_string(path.with_extension("answers"))?)?;
+    let params = crate::read_sidecar_params(path)?;
+    params.validate(solution.params)?;
+
+    if answers.part1.is_none() && answers.part2.is_none() {
+        return Err("Example has no expected answers".into());
+    }
+
+    let parts = [(1, answers.part1, solution.part1), (2, answers.part2, solution.part2)];
+    for (part, expected, solve) in parts {
+        if let Some(expected) = expected {
+            let actual = solve(&input, &params);
+            if actual != expected {
+                return Err(format!("Expected {expected} for part {part}, got {actual}").into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Generates a test for each example input of the given day; see [`examples`](crate::examples).
+#[macro_export]
+macro_rules! aoc_test {
+    ($day:ident) => {
+        mod examples {
+            fn check(file_name: &str) {
+                let path = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("examples")
+                    .join(stringify!($day))
+                    .join(file_name);
+                $crate::examples::check(&$crate::$day::SOLUTION, &path);
+            }
+
+            include!(concat!(env!("OUT_DIR"), "/examples/", stringify!($day), ".rs"));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let answers = Answers::parse("# Sample\npart1 = 40\n\npart2=25272\n").unwrap();
+        assert_eq!(Some("40".into()), answers.part1);
+        assert_eq!(Some("25272".into()), answers.part2);
+        assert_eq!(None, answers.ignore);
+
+        let answers = Answers::parse("part1 = 2\nignore = Too slow").unwrap();
+        assert_eq!(Some("Too slow".into()), answers.ignore);
+
+        assert_eq!("part1 = 2\nignore = Too slow\n", answers.to_string());
+
+        assert_eq!(Err(AnswersError::Malformed("40".into())), Answers::parse("40"));
+        assert_eq!(Err(AnswersError::UnknownKey("part3".into())), Answers::parse("part3 = 1"));
+    }
+}
@@ -0,0 +1,159 @@
+//! Downloading and caching of puzzle inputs
+//!
+//! Inputs are downloaded with the session cookie of a logged-in browser session, which is read
+//! from the `AOCSESSION` environment variable or else from `~/.adventofcode.session`. Downloaded
+//! inputs are cached, and a cached input is never downloaded again.
+//!
+//! The HTTP request itself is made by `aoc fetch`, which is only built with the `fetch` feature so
+//! that the solvers don't depend on an HTTP client. The base URL defaults to the real site but can
+//! be changed, e.g. to a local server in tests.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u32 = 2025;
+
+/// Identifies the tool to the site's operators, as they request for automated downloads.
+pub const USER_AGENT: &str = concat!(
+    "github.com/jsgroth/advent-of-code-2025 aoc-fetch/",
+    env!("CARGO_PKG_VERSION"),
+    " (ureq)"
+);
+
+const SESSION_VAR: &str = "AOCSESSION";
+const SESSION_FILE: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession,
+    Status { code: u16, body: String },
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSession => write!(
+                f,
+                "No session token; set {SESSION_VAR} or put the token in ~/{SESSION_FILE}"
+            ),
+            Self::Status { code, body } => {
+                write!(f, "Request failed with status {code}: {}", body.trim())
+            }
+            Self::Transport(err) => write!(f, "Request failed: {err}"),
+            Self::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Reads the session token from the environment, or else from the session file in the home
+/// directory.
+pub fn find_session() -> Result<String, FetchError> {
+    if let Ok(session) = env::var(SESSION_VAR)
+        && !session.trim().is_empty()
+    {
+        return Ok(session.trim().into());
+    }
+
+    let home = env::var_os("HOME").ok_or(FetchError::MissingSession)?;
+    match fs::read_to_string(Path::new(&home).join(SESSION_FILE)) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().into()),
+        Ok(_) => Err(FetchError::MissingSession),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Err(FetchError::MissingSession),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// The URL of the input for the given day, on the site at the given base URL.
+pub fn input_url(base_url: &str, day: usize) -> String {
+    format!("{}/{YEAR}/day/{day}/input", base_url.trim_end_matches('/'))
+}
+
+/// The headers to send with a download request made with the given session token.
+pub fn request_headers(session: &str) -> [(&'static str, String); 2] {
+    [("User-Agent", USER_AGENT.into()), ("Cookie", format!("session={session}"))]
+}
+
+pub fn cache_path(cache_dir: &Path, day: usize) -> PathBuf {
+    cache_dir.join(format!("day{day}.txt"))
+}
+
+/// Returns the path of the cached input for the given day, downloading and caching it first if
+/// it isn't cached yet. The input is written to a temporary file and then renamed, so a failed
+/// download never leaves behind a partial input.
+pub fn cached_input(
+    cache_dir: &Path,
+    day: usize,
+    download: impl FnOnce() -> Result<String, FetchError>,
+) -> Result<PathBuf, FetchError> {
+    let path = cache_path(cache_dir, day);
+    if path.exists() {
+        return Ok(path);
+    }
+
+    let input = download()?;
+    fs::create_dir_all(cache_dir)?;
+    let partial_path = path.with_extension("partial");
+    fs::write(&partial_path, input)?;
+    fs::rename(&partial_path, &path)?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn downloads_once() {
+        let cache_dir = env::temp_dir().join(format!("aoc-fetch-once-{}", std::process::id()));
+        let downloads = Cell::new(0);
+        let download = || {
+            downloads.set(downloads.get() + 1);
+            Ok("1\n2\n".into())
+        };
+
+        for _ in 0..2 {
+            let path = cached_input(&cache_dir, 3, download).unwrap();
+            assert_eq!("1\n2\n", fs::read_to_string(path).unwrap());
+        }
+        fs::remove_dir_all(&cache_dir).unwrap();
+        assert_eq!(1, downloads.get());
+    }
+
+    #[test]
+    fn failed_download() {
+        let cache_dir = env::temp_dir().join(format!("aoc-fetch-failed-{}", std::process::id()));
+        let download = || Err(FetchError::Status { code: 400, body: "Please log in\n".into() });
+
+        let err = cached_input(&cache_dir, 1, download).unwrap_err();
+        assert_eq!("Request failed with status 400: Please log in", err.to_string());
+        assert!(!cache_path(&cache_dir, 1).exists());
+    }
+
+    #[test]
+    fn request() {
+        assert_eq!(
+            "http://localhost:8000/2025/day/1/input",
+            input_url("http://localhost:8000/", 1)
+        );
+        assert_eq!(
+            [("User-Agent", USER_AGENT.into()), ("Cookie", "session=abc123".into())],
+            request_headers("abc123")
+        );
+        assert!(USER_AGENT.contains(env!("CARGO_PKG_VERSION")), "{USER_AGENT}");
+    }
+}
@@ -0,0 +1,454 @@
+//! Deterministic random puzzle inputs, for testing and benchmarking beyond the real inputs
+//!
+//! Every generator produces an input in the same format as the real puzzle input, and the same
+//! seed and size always produce the same input. Sizes default to roughly the size of the real
+//! inputs. What the size controls differs per day:
+//!
+//! | Day | Size |
+//! |-----|------|
+//! | 1 | Number of rotations |
+//! | 2 | Number of ID ranges |
+//! | 3 | Number of battery banks |
+//! | 4 | Width and height of the grid |
+//! | 5 | Number of fresh ID ranges (with 5 available IDs per range) |
+//! | 6 | Number of problems |
+//! | 7 | Width of the manifold |
+//! | 8 | Number of junction boxes |
+//! | 9 | Number of columns in the polygon (with 4 vertices per column) |
+//! | 10 | Number of machines |
+//! | 11 | Number of devices |
+//! | 12 | Number of regions |
+//!
+//! Inputs are generated to share the properties of the real inputs, some of which the solutions
+//! rely on, e.g. day 10 machines always have a solution with at least one button press, and day 12
+//! regions either trivially fit their presents or can't possibly fit them. Day 7 manifolds follow
+//! the real inputs' layout of splitters, which also means that no beam can loop forever, so every
+//! generated manifold has a timeline count.
+
+use crate::rng::Rng;
+use rustc_hash::FxHashSet;
+use std::fmt::Write;
+
+pub const DAYS: usize = 12;
+
+/// Roughly the size of the real input for the given day.
+pub fn default_size(day: usize) -> Option<usize> {
+    let size = match day {
+        1 => 4500,
+        2 => 35,
+        3 => 200,
+        4 => 140,
+        5 => 180,
+        6 => 1000,
+        7 => 141,
+        8 => 1000,
+        9 => 124,
+        // Kept small since the day 10 solution is slow on machines that aren't shaped like the
+        // real input's
+        10 => 20,
+        11 => 600,
+        12 => 1000,
+        _ => return None,
+    };
+    Some(size)
+}
+
+/// Generates an input for the given day, or returns `None` if there is no such day.
+pub fn generate(day: usize, seed: u64, size: usize) -> Option<String> {
+    let generator = match day {
+        1 => day1,
+        2 => day2,
+        3 => day3,
+        4 => day4,
+        5 => day5,
+        6 => day6,
+        7 => day7,
+        8 => day8,
+        9 => day9,
+        10 => day10,
+        11 => day11,
+        12 => day12,
+        _ => return None,
+    };
+
+    // Mix the day into the seed so that the same seed gives unrelated inputs for different days
+    let mut rng = Rng::new(seed ^ (day as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+    Some(generator(&mut rng, size))
+}
+
+fn join_lines(lines: impl IntoIterator<Item = String>) -> String {
+    let mut s = String::new();
+    for line in lines {
+        writeln!(s, "{line}").unwrap();
+    }
+    s
+}
+
+fn day1(rng: &mut Rng, size: usize) -> String {
+    join_lines((0..size).map(|_| {
+        let direction = if rng.chance(1, 2) { 'L' } else { 'R' };
+        format!("{direction}{}", rng.range(1..=999))
+    }))
+}
+
+fn day2(rng: &mut Rng, size: usize) -> String {
+    let mut ranges: Vec<(u64, u64)> = Vec::with_capacity(size);
+    while ranges.len() < size {
+        let digits = rng.range(1..=10) as u32;
+        let start = rng.range(10_u64.pow(digits - 1)..=10_u64.pow(digits) - 1);
+        let end = start + rng.range(0..=10_u64.pow(digits.min(6)));
+
+        if ranges.iter().all(|&(other_start, other_end)| end < other_start || start > other_end) {
+            ranges.push((start, end));
+        }
+    }
+
+    let ranges: Vec<_> = ranges.into_iter().map(|(start, end)| format!("{start}-{end}")).collect();
+    ranges.join(",") + "\n"
+}
+
+fn day3(rng: &mut Rng, size: usize) -> String {
+    join_lines((0..size).map(|_| (0..100).map(|_| char::from(b'1' + rng.below(9) as u8)).collect()))
+}
+
+fn day4(rng: &mut Rng, size: usize) -> String {
+    join_lines(
+        (0..size).map(|_| (0..size).map(|_| if rng.chance(2, 3) { '@' } else { '.' }).collect()),
+    )
+}
+
+fn day5(rng: &mut Rng, size: usize) -> String {
+    const MAX_ID: u64 = 500_000_000_000_000;
+
+    let ranges: Vec<_> = (0..size)
+        .map(|_| {
+            let start = rng.range(1..=MAX_ID);
+            (start, start + rng.range(0..=MAX_ID / 50))
+        })
+        .collect();
+
+    // About half of the available IDs are chosen from within a fresh range
+    let ids = (0..5 * size).map(|_| {
+        if rng.chance(1, 2) {
+            let &(start, end) = rng.choose(&ranges);
+            rng.range(start..=end)
+        } else {
+            rng.range(1..=MAX_ID + MAX_ID / 50)
+        }
+    });
+
+    let mut s = join_lines(ranges.iter().map(|(start, end)| format!("{start}-{end}")));
+    s.push('\n');
+    s + &join_lines(ids.map(|id| id.to_string()))
+}
+
+fn day6(rng: &mut Rng, size: usize) -> String {
+    const OPERAND_ROWS: usize = 4;
+
+    let mut rows = vec![String::new(); OPERAND_ROWS + 1];
+    for problem in 0..size {
+        if problem != 0 {
+            rows.iter_mut().for_each(|row| row.push(' '));
+        }
+
+        let operands: Vec<_> = (0..OPERAND_ROWS)
+            .map(|_| {
+                let digits = rng.range(1..=4) as u32;
+                rng.range(1..=10_u64.pow(digits) - 1)
+            })
+            .collect();
+        let width = operands.iter().map(|operand| operand.to_string().len()).max().unwrap();
+        let left_aligned = rng.chance(1, 2);
+
+        for (row, operand) in rows.iter_mut().zip(&operands) {
+            if left_aligned {
+                write!(row, "{operand:<width$}").unwrap();
+            } else {
+                write!(row, "{operand:>width$}").unwrap();
+            }
+        }
+
+        let operator = if rng.chance(1, 2) { '+' } else { '*' };
+        write!(rows[OPERAND_ROWS], "{operator:<width$}").unwrap();
+    }
+
+    join_lines(rows)
+}
+
+fn day7(rng: &mut Rng, size: usize) -> String {
+    // Splitters are only placed where a beam could reach them, on every other row, at every other
+    // column, and never on the edges
+    let center = size.max(3) / 2;
+    let width = 2 * center + 1;
+
+    let mut rows = vec![vec!['.'; width]; 2 * center + 2];
+    rows[0][center] = 'S';
+    for i in 1..=center {
+        for x in (center + 1 - i..center + i).step_by(2) {
+            if rng.chance(2, 3) {
+                rows[2 * i][x] = '^';
+            }
+        }
+    }
+
+    join_lines(rows.into_iter().map(String::from_iter))
+}
+
+fn day8(rng: &mut Rng, size: usize) -> String {
+    join_lines((0..size).map(|_| {
+        let [x, y, z] = [(); 3].map(|()| rng.range(0..=99_999));
+        format!("{x},{y},{z}")
+    }))
+}
+
+fn day9(rng: &mut Rng, size: usize) -> String {
+    // A polygon made of adjacent vertical strips. Every strip's top is above every strip's bottom,
+    // so neighboring strips always overlap, and neighboring tops and bottoms differ so that there
+    // are no straight corners
+    let size = size.max(1);
+
+    let mut xs = vec![rng.range(0..=1000)];
+    for _ in 0..size {
+        xs.push(xs.last().unwrap() + rng.range(1..=1500));
+    }
+
+    let mut tops: Vec<u64> = Vec::with_capacity(size);
+    let mut bottoms: Vec<u64> = Vec::with_capacity(size);
+    for _ in 0..size {
+        let top = loop {
+            let top = rng.range(50_000..=99_999);
+            if tops.last() != Some(&top) {
+                break top;
+            }
+        };
+        let bottom = loop {
+            let bottom = rng.range(0..=49_999);
+            if bottoms.last() != Some(&bottom) {
+                break bottom;
+            }
+        };
+        tops.push(top);
+        bottoms.push(bottom);
+    }
+
+    let top_edge = (0..size).flat_map(|i| [(xs[i], tops[i]), (xs[i + 1], tops[i])]);
+    let bottom_edge = (0..size).rev().flat_map(|i| [(xs[i + 1], bottoms[i]), (xs[i], bottoms[i])]);
+    join_lines(top_edge.chain(bottom_edge).map(|(x, y)| format!("{x},{y}")))
+}
+
+fn day10(rng: &mut Rng, size: usize) -> String {
+    join_lines((0..size).map(|_| {
+        let counters = rng.range(3..=8) as usize;
+        let buttons_len = rng.range(counters as u64 / 2 + 1..=counters as u64 + 2) as usize;
+
+        let mut buttons: Vec<Vec<usize>> = (0..buttons_len)
+            .map(|_| {
+                let mut button: Vec<_> = (0..counters).filter(|_| rng.chance(2, 5)).collect();
+                if button.is_empty() {
+                    button.push(rng.index(counters));
+                }
+                button
+            })
+            .collect();
+
+        // Every counter needs a button that affects it, or else its requirement is forced to 0
+        for counter in 0..counters {
+            if !buttons.iter().any(|button| button.contains(&counter)) {
+                let i = rng.index(buttons.len());
+                buttons[i].push(counter);
+                buttons[i].sort_unstable();
+            }
+        }
+
+        // Requirements are built from actual button presses so that every machine has a solution
+        let mut indicators = vec![false; counters];
+        let mut joltages = vec![0; counters];
+        for button in &buttons {
+            let toggled = rng.chance(1, 2);
+            let presses = rng.range(0..=15);
+            for &counter in button {
+                indicators[counter] ^= toggled;
+                joltages[counter] += presses;
+            }
+        }
+
+        // Real machines never start with their indicators already in the target state
+        if !indicators.contains(&true) {
+            for &counter in rng.choose(&buttons) {
+                indicators[counter] = true;
+            }
+        }
+
+        let indicators: String = indicators.iter().map(|&on| if on { '#' } else { '.' }).collect();
+        let buttons: Vec<_> = buttons
+            .iter()
+            .map(|button| {
+                let counters: Vec<_> = button.iter().map(usize::to_string).collect();
+                format!("({})", counters.join(","))
+            })
+            .collect();
+        let joltages: Vec<_> = joltages.iter().map(u64::to_string).collect();
+
+        format!("[{indicators}] {} {{{}}}", buttons.join(" "), joltages.join(","))
+    }))
+}
+
+fn day11(rng: &mut Rng, size: usize) -> String {
+    const RESERVED: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+
+    let size = size.max(RESERVED.len());
+    let mut used: FxHashSet<String> = RESERVED.iter().map(|&name| name.into()).collect();
+    let mut names: Vec<String> = Vec::with_capacity(size);
+    while names.len() < size - RESERVED.len() {
+        let name: String = (0..3).map(|_| char::from(b'a' + rng.below(26) as u8)).collect();
+        if used.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    // Nodes are arranged in layers with edges only between consecutive layers, and with roughly
+    // the same number of layers regardless of size so that path counts stay in a reasonable
+    // range. Every node gets at least one successor and one predecessor, so every node is on some
+    // svr -> out path, and placing fft and dac in different layers makes svr -> fft -> dac -> out
+    // paths exist
+    const LAYERS: usize = 40;
+    rng.shuffle(&mut names);
+    let width = names.len().div_ceil(LAYERS).max(2);
+    let mut layers: Vec<Vec<&str>> = vec![vec!["svr"]];
+    layers.extend(names.chunks(width).map(|chunk| chunk.iter().map(String::as_str).collect()));
+    while layers.len() < 4 {
+        layers.push(vec![]);
+    }
+    let len = layers.len();
+    layers[1].push("you");
+    layers[len / 3].push("fft");
+    layers[(2 * len / 3).max(len / 3 + 1)].push("dac");
+    layers.retain(|layer| !layer.is_empty());
+    layers.push(vec!["out"]);
+
+    let mut lines = vec![];
+    for pair in layers.windows(2) {
+        let [layer, next] = pair else { unreachable!() };
+        let mut successors: Vec<FxHashSet<&str>> = layer
+            .iter()
+            .map(|_| {
+                let edges_len = (rng.range(1..=3) as usize).min(next.len());
+                let mut successors = FxHashSet::default();
+                while successors.len() < edges_len {
+                    successors.insert(*rng.choose(next));
+                }
+                successors
+            })
+            .collect();
+        for &node in next {
+            if !successors.iter().any(|successors| successors.contains(node)) {
+                successors[rng.index(layer.len())].insert(node);
+            }
+        }
+
+        lines.extend(layer.iter().zip(successors).map(|(node, successors)| {
+            let mut successors: Vec<_> = successors.into_iter().collect();
+            successors.sort_unstable();
+            format!("{node}: {}", successors.join(" "))
+        }));
+    }
+
+    rng.shuffle(&mut lines);
+    join_lines(lines)
+}
+
+fn day12(rng: &mut Rng, size: usize) -> String {
+    const SHAPES: usize = 6;
+
+    let mut s = String::new();
+    for i in 0..SHAPES {
+        writeln!(s, "{i}:").unwrap();
+
+        let mut cells = [[false; 3]; 3];
+        while cells.iter().flatten().filter(|&&cell| cell).count() < 5 {
+            cells[rng.index(3)][rng.index(3)] = true;
+        }
+        for row in cells {
+            let row: String = row.iter().map(|&cell| if cell { '#' } else { '.' }).collect();
+            writeln!(s, "{row}").unwrap();
+        }
+        s.push('\n');
+    }
+
+    for _ in 0..size {
+        let width = rng.range(35..=50) as usize;
+        let height = rng.range(35..=50) as usize;
+
+        // Either few enough presents that each fits in its own 3x3 block, or more 3x3 blocks worth
+        // of presents than the region's area
+        let blocks = (width / 3) * (height / 3);
+        let total = if rng.chance(1, 2) {
+            rng.range(blocks as u64 / 2..=blocks as u64) as usize
+        } else {
+            width * height / 5 + 1 + rng.index(blocks / 4 + 1)
+        };
+
+        let mut counts = [0; SHAPES];
+        for _ in 0..total {
+            counts[rng.index(SHAPES)] += 1;
+        }
+        let counts: Vec<_> = counts.iter().map(usize::to_string).collect();
+        writeln!(s, "{width}x{height}: {}", counts.join(" ")).unwrap();
+    }
+
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point2D;
+    use crate::day7::{self, EdgePolicy, Manifold};
+    use crate::graph::Graph;
+    use crate::paths::{self, PathQuery};
+    use crate::polygon::RectilinearPolygon;
+
+    #[test]
+    fn deterministic() {
+        for day in 1..=DAYS {
+            let a = generate(day, 123, 20).unwrap();
+            let b = generate(day, 123, 20).unwrap();
+            let c = generate(day, 124, 20).unwrap();
+            assert_eq!(a, b, "day {day}");
+            assert_ne!(a, c, "day {day}");
+            assert!(a.ends_with('\n'), "day {day}");
+        }
+
+        assert_eq!(None, generate(13, 123, 20));
+        assert_eq!(None, default_size(0));
+    }
+
+    #[test]
+    fn valid_structures() {
+        for seed in 0..20 {
+            let input = generate(9, seed, 30).unwrap();
+            let vertices = input
+                .lines()
+                .map(|line| {
+                    let (x, y) = line.split_once(',').unwrap();
+                    Point2D { x: x.parse().unwrap(), y: y.parse().unwrap() }
+                })
+                .collect();
+            let result = RectilinearPolygon::new(vertices);
+            assert!(result.is_ok(), "seed {seed}: {result:?}");
+
+            let input = generate(11, seed, 100).unwrap();
+            let graph = Graph::from_adjacency(input.lines().map(|line| {
+                let (name, edges) = line.split_once(": ").unwrap();
+                (name, edges.split(' '))
+            }));
+            let query = PathQuery { required: vec!["fft", "dac"], ..PathQuery::new("svr", "out") };
+            assert!(paths::count_paths(&graph, &query).unwrap() > 0, "seed {seed}");
+            assert!(paths::count_paths(&graph, &PathQuery::new("you", "out")).unwrap() > 0);
+
+            let Manifold { grid, start } = day7::parse(&generate(7, seed, 15).unwrap());
+            let counted = day7::count_timelines(&grid, start, EdgePolicy::Exit);
+            assert!(counted.is_ok(), "seed {seed}: {counted:?}");
+        }
+    }
+}
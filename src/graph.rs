@@ -0,0 +1,250 @@
+//! Directed graphs over string-named nodes, with names interned into dense IDs
+//!
+//! All traversals are iterative so that deep graphs can't overflow the stack.
+
+use rustc_hash::FxHashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    /// Node names along the cycle, starting and ending with the same node
+    pub cycle: Vec<String>,
+}
+
+impl Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Graph contains a cycle: {}", self.cycle.join(" -> "))
+    }
+}
+
+impl Error for CycleError {}
+
+#[derive(Debug, Clone, Default)]
+pub struct Graph<'a> {
+    names: Vec<&'a str>,
+    ids: FxHashMap<&'a str, usize>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl<'a> Graph<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a graph from each node's list of successors. Successors that never appear as a
+    /// source are added as nodes with no outgoing edges.
+    pub fn from_adjacency<I>(adjacency: impl IntoIterator<Item = (&'a str, I)>) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut graph = Self::new();
+        for (name, successors) in adjacency {
+            let id = graph.intern(name);
+            for successor in successors {
+                let successor_id = graph.intern(successor);
+                graph.edges[id].push(successor_id);
+            }
+        }
+
+        graph
+    }
+
+    /// Returns the ID for the given name, adding a new node if it doesn't exist yet.
+    pub fn intern(&mut self, name: &'a str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = self.names.len();
+        self.names.push(name);
+        self.ids.insert(name, id);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    pub fn add_edge(&mut self, from: &'a str, to: &'a str) {
+        let from = self.intern(from);
+        let to = self.intern(to);
+        self.edges[from].push(to);
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &'a str {
+        self.names[id]
+    }
+
+    pub fn successors(&self, id: usize) -> &[usize] {
+        &self.edges[id]
+    }
+
+    fn predecessor_lists(&self) -> Vec<Vec<usize>> {
+        let mut predecessors = vec![Vec::new(); self.len()];
+        for (id, successors) in self.edges.iter().enumerate() {
+            for &successor in successors {
+                predecessors[successor].push(id);
+            }
+        }
+
+        predecessors
+    }
+
+    /// Marks every node reachable from the source (including the source itself).
+    pub fn reachable_from(&self, source: usize) -> Vec<bool> {
+        self.reachable_within(source, &self.edges, &vec![true; self.len()])
+    }
+
+    /// Marks every node that can reach the sink (including the sink itself).
+    pub fn reaching(&self, sink: usize) -> Vec<bool> {
+        self.reachable_within(sink, &self.predecessor_lists(), &vec![true; self.len()])
+    }
+
+    // Flood fill that never enters nodes not marked as allowed
+    fn reachable_within(&self, start: usize, edges: &[Vec<usize>], allowed: &[bool]) -> Vec<bool> {
+        let mut reached = vec![false; self.len()];
+        if !allowed[start] {
+            return reached;
+        }
+
+        reached[start] = true;
+        let mut stack = vec![start];
+        while let Some(id) = stack.pop() {
+            for &next in &edges[id] {
+                if allowed[next] && !reached[next] {
+                    reached[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+
+        reached
+    }
+
+    /// Marks every node that lies on some path from the source to the sink without passing
+    /// through a node that is not allowed.
+    pub fn between(&self, source: usize, sink: usize, allowed: &[bool]) -> Vec<bool> {
+        let from_source = self.reachable_within(source, &self.edges, allowed);
+        let to_sink = self.reachable_within(sink, &self.predecessor_lists(), allowed);
+
+        from_source.iter().zip(to_sink).map(|(&a, b)| a && b).collect()
+    }
+
+    /// Returns every node in topological order, or the first cycle found.
+    pub fn topological_order(&self) -> Result<Vec<usize>, CycleError> {
+        self.topological_order_within(&vec![true; self.len()])
+    }
+
+    /// Topological order of the subgraph induced by the nodes marked as included.
+    pub fn topological_order_within(&self, included: &[bool]) -> Result<Vec<usize>, CycleError> {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum State {
+            Unvisited,
+            InProgress,
+            Done,
+        }
+
+        let mut states = vec![State::Unvisited; self.len()];
+        let mut postorder = Vec::with_capacity(self.len());
+
+        for root in (0..self.len()).filter(|&id| included[id]) {
+            if states[root] != State::Unvisited {
+                continue;
+            }
+
+            // Each stack entry is a node and the index of the next successor to visit. The stack
+            // is always exactly the current DFS path, which is what gets reported for cycles
+            states[root] = State::InProgress;
+            let mut stack = vec![(root, 0)];
+            while let Some(&mut (id, ref mut next_edge)) = stack.last_mut() {
+                let Some(&next) = self.edges[id].get(*next_edge) else {
+                    states[id] = State::Done;
+                    postorder.push(id);
+                    stack.pop();
+                    continue;
+                };
+                *next_edge += 1;
+
+                if !included[next] {
+                    continue;
+                }
+
+                match states[next] {
+                    State::Unvisited => {
+                        states[next] = State::InProgress;
+                        stack.push((next, 0));
+                    }
+                    State::InProgress => {
+                        let cycle_start = stack.iter().position(|&(id, _)| id == next).unwrap();
+                        let cycle = stack[cycle_start..]
+                            .iter()
+                            .map(|&(id, _)| self.names[id].to_string())
+                            .chain([self.names[next].to_string()])
+                            .collect();
+                        return Err(CycleError { cycle });
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+
+        postorder.reverse();
+        Ok(postorder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topological_order() {
+        let graph = Graph::from_adjacency([
+            ("a", vec!["b", "c"]),
+            ("b", vec!["d"]),
+            ("c", vec!["d", "e"]),
+            ("d", vec!["e"]),
+        ]);
+
+        let order = graph.topological_order().unwrap();
+        let position = |name| order.iter().position(|&id| graph.name(id) == name).unwrap();
+        assert_eq!(5, order.len());
+        assert!(position("a") < position("b"));
+        assert!(position("b") < position("d"));
+        assert!(position("c") < position("d"));
+        assert!(position("d") < position("e"));
+
+        let between = graph.between(graph.id("b").unwrap(), graph.id("e").unwrap(), &[true; 5]);
+        let between: Vec<_> = (0..5).filter(|&id| between[id]).map(|id| graph.name(id)).collect();
+        assert_eq!(vec!["b", "d", "e"], between);
+    }
+
+    #[test]
+    fn cycle() {
+        let graph = Graph::from_adjacency([
+            ("a", vec!["b"]),
+            ("b", vec!["c", "x"]),
+            ("c", vec!["d"]),
+            ("d", vec!["b"]),
+        ]);
+
+        let err = graph.topological_order().unwrap_err();
+        assert_eq!(vec!["b", "c", "d", "b"], err.cycle);
+        assert_eq!("Graph contains a cycle: b -> c -> d -> b", err.to_string());
+
+        // Excluding part of the cycle makes the rest of the graph acyclic
+        let mut included = vec![true; graph.len()];
+        included[graph.id("d").unwrap()] = false;
+        assert!(graph.topological_order_within(&included).is_ok());
+    }
+}
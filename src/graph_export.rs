@@ -0,0 +1,194 @@
+//! Text exports of graphs as Graphviz DOT and Mermaid flowcharts
+//!
+//! The source, sink, required and forbidden nodes of a path query are highlighted, and if path
+//! counts are provided then each node is annotated with the number of paths reaching it from the
+//! source.
+
+use crate::graph::Graph;
+use crate::paths::{PathCounts, PathQuery};
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Source,
+    Sink,
+    Required,
+    Forbidden,
+    Other,
+}
+
+impl Role {
+    fn of(name: &str, query: &PathQuery<'_>) -> Self {
+        if name == query.source {
+            Self::Source
+        } else if name == query.sink {
+            Self::Sink
+        } else if query.required.contains(&name) {
+            Self::Required
+        } else if query.forbidden.contains(&name) {
+            Self::Forbidden
+        } else {
+            Self::Other
+        }
+    }
+
+    fn color(self) -> Option<&'static str> {
+        match self {
+            Self::Source => Some("#8fd694"),
+            Self::Sink => Some("#f08080"),
+            Self::Required => Some("#ffd700"),
+            Self::Forbidden => Some("#a9a9a9"),
+            Self::Other => None,
+        }
+    }
+
+    fn class_name(self) -> &'static str {
+        match self {
+            Self::Source => "source",
+            Self::Sink => "sink",
+            Self::Required => "required",
+            Self::Forbidden => "forbidden",
+            Self::Other => "other",
+        }
+    }
+}
+
+const HIGHLIGHTED_ROLES: [Role; 4] = [Role::Source, Role::Sink, Role::Required, Role::Forbidden];
+
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(s: &str) -> String {
+    s.replace('"', "#quot;").replace('<', "#lt;").replace('>', "#gt;")
+}
+
+fn count_label(counts: &PathCounts, id: usize) -> String {
+    match counts.to_node(id) {
+        Ok(count) => count.to_string(),
+        Err(_) => "overflow".into(),
+    }
+}
+
+/// Renders the graph in Graphviz DOT format.
+pub fn to_dot(graph: &Graph<'_>, query: &PathQuery<'_>, counts: Option<&PathCounts>) -> String {
+    let mut dot = String::new();
+    writeln!(dot, "digraph {{").unwrap();
+    writeln!(dot, "    rankdir=LR;").unwrap();
+    writeln!(dot, "    node [shape=box, style=rounded];").unwrap();
+
+    for id in 0..graph.len() {
+        let name = graph.name(id);
+        let mut label = escape_dot(name);
+        if let Some(counts) = counts {
+            write!(label, "\\n{}", count_label(counts, id)).unwrap();
+        }
+
+        write!(dot, "    n{id} [label=\"{label}\"").unwrap();
+        if let Some(color) = Role::of(name, query).color() {
+            write!(dot, ", style=\"rounded,filled\", fillcolor=\"{color}\"").unwrap();
+        }
+        writeln!(dot, "];").unwrap();
+    }
+
+    for id in 0..graph.len() {
+        for &successor in graph.successors(id) {
+            writeln!(dot, "    n{id} -> n{successor};").unwrap();
+        }
+    }
+
+    writeln!(dot, "}}").unwrap();
+    dot
+}
+
+/// Renders the graph as a Mermaid flowchart.
+pub fn to_mermaid(graph: &Graph<'_>, query: &PathQuery<'_>, counts: Option<&PathCounts>) -> String {
+    let mut mermaid = String::new();
+    writeln!(mermaid, "flowchart LR").unwrap();
+
+    for id in 0..graph.len() {
+        let name = graph.name(id);
+        let mut label = escape_mermaid(name);
+        if let Some(counts) = counts {
+            write!(label, "<br/>{}", count_label(counts, id)).unwrap();
+        }
+
+        writeln!(mermaid, "    n{id}[\"{label}\"]").unwrap();
+    }
+
+    for id in 0..graph.len() {
+        for &successor in graph.successors(id) {
+            writeln!(mermaid, "    n{id} --> n{successor}").unwrap();
+        }
+    }
+
+    for role in HIGHLIGHTED_ROLES {
+        let ids: Vec<_> = (0..graph.len())
+            .filter(|&id| Role::of(graph.name(id), query) == role)
+            .map(|id| format!("n{id}"))
+            .collect();
+        if ids.is_empty() {
+            continue;
+        }
+
+        let class_name = role.class_name();
+        writeln!(mermaid, "    classDef {class_name} fill:{}", role.color().unwrap()).unwrap();
+        writeln!(mermaid, "    class {} {class_name}", ids.join(",")).unwrap();
+    }
+
+    mermaid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths;
+
+    fn test_graph() -> Graph<'static> {
+        Graph::from_adjacency([("a", vec!["b", "c"]), ("b", vec!["d"]), ("c", vec!["d"])])
+    }
+
+    #[test]
+    fn dot() {
+        let graph = test_graph();
+        let query = PathQuery { required: vec!["b"], ..PathQuery::new("a", "d") };
+        let counts = paths::path_counts(&graph, &query).unwrap();
+
+        let expected = r##"digraph {
+    rankdir=LR;
+    node [shape=box, style=rounded];
+    n0 [label="a\n1", style="rounded,filled", fillcolor="#8fd694"];
+    n1 [label="b\n1", style="rounded,filled", fillcolor="#ffd700"];
+    n2 [label="c\n1"];
+    n3 [label="d\n2", style="rounded,filled", fillcolor="#f08080"];
+    n0 -> n1;
+    n0 -> n2;
+    n1 -> n3;
+    n2 -> n3;
+}
+"##;
+        assert_eq!(expected, to_dot(&graph, &query, Some(&counts)));
+    }
+
+    #[test]
+    fn mermaid() {
+        let graph = test_graph();
+        let query = PathQuery::new("a", "d");
+
+        let expected = r##"flowchart LR
+    n0["a"]
+    n1["b"]
+    n2["c"]
+    n3["d"]
+    n0 --> n1
+    n0 --> n2
+    n1 --> n3
+    n2 --> n3
+    classDef source fill:#8fd694
+    class n0 source
+    classDef sink fill:#f08080
+    class n3 sink
+"##;
+        assert_eq!(expected, to_mermaid(&graph, &query, None));
+    }
+}
@@ -0,0 +1,303 @@
+//! Largest axis-aligned rectangle inscribed in a rectilinear polygon
+//!
+//! Rectangles are measured in tiles, i.e. by the number of lattice points they cover, so a
+//! rectangle from (0, 0) to (2, 1) has a tile area of 6.
+//!
+//! A maximal rectangle can always be grown until each side touches a polygon coordinate, so the
+//! search only needs to consider the distinct vertex coordinates (plus the fixed corner, if any).
+//! These are compressed into a grid of alternating lines and open intervals, where each grid
+//! element is either entirely inside or entirely outside the polygon. A 2D prefix sum over the
+//! outside elements then answers "is this rectangle inside the polygon" in constant time.
+
+use crate::Point2D;
+use crate::polygon::RectilinearPolygon;
+use std::cmp;
+
+type Point = Point2D<i64>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CornerConstraint {
+    /// Two opposite corners must be polygon vertices
+    Vertices,
+    /// Corners can be any lattice points
+    Lattice,
+    /// One corner is fixed at the given point; the opposite corner can be any lattice point
+    Fixed(Point),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rectangle {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rectangle {
+    pub fn from_corners(a: Point, b: Point) -> Self {
+        Self {
+            min: Point { x: cmp::min(a.x, b.x), y: cmp::min(a.y, b.y) },
+            max: Point { x: cmp::max(a.x, b.x), y: cmp::max(a.y, b.y) },
+        }
+    }
+
+    /// Number of lattice points covered by the rectangle, including its boundary.
+    pub fn tile_area(self) -> i64 {
+        (self.max.x - self.min.x + 1) * (self.max.y - self.min.y + 1)
+    }
+}
+
+struct CompressedPolygon {
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    // Prefix sums of outside elements over the doubled grid, with an extra leading row and column
+    outside_prefix: Vec<Vec<u32>>,
+}
+
+fn compress(coords: impl Iterator<Item = i64>) -> Vec<i64> {
+    let mut coords: Vec<_> = coords.collect();
+    coords.sort_unstable();
+    coords.dedup();
+    coords
+}
+
+fn index_of(coords: &[i64], value: i64) -> usize {
+    coords.binary_search(&value).unwrap()
+}
+
+impl CompressedPolygon {
+    fn new(polygon: &RectilinearPolygon, extra: Option<Point>) -> Self {
+        let points = || polygon.vertices().iter().copied().chain(extra);
+        let xs = compress(points().map(|point| point.x));
+        let ys = compress(points().map(|point| point.y));
+
+        let cols = 2 * xs.len() - 1;
+        let rows = 2 * ys.len() - 1;
+
+        // Locating each element separately would take O(n) per element, so instead mark the
+        // boundary, and mark each vertical edge in the rows where a ray cast in the +X direction
+        // crosses it (half-open Y ranges, as in `RectilinearPolygon::locate`). Then one sweep per
+        // row finds the elements with an odd number of crossings to their right
+        let mut boundary = vec![vec![false; cols]; rows];
+        let mut crossings = vec![vec![false; cols]; rows];
+        let vertices = polygon.vertices();
+        for (k, &start) in vertices.iter().enumerate() {
+            let end = vertices[(k + 1) % vertices.len()];
+            let (col0, col1) = (2 * index_of(&xs, start.x), 2 * index_of(&xs, end.x));
+            let (row0, row1) = (2 * index_of(&ys, start.y), 2 * index_of(&ys, end.y));
+            let (col0, col1) = (cmp::min(col0, col1), cmp::max(col0, col1));
+            let (row0, row1) = (cmp::min(row0, row1), cmp::max(row0, row1));
+
+            for row in row0..=row1 {
+                boundary[row][col0..=col1].fill(true);
+                if col0 == col1 && row < row1 {
+                    crossings[row][col0] = true;
+                }
+            }
+        }
+
+        let mut outside_prefix = vec![vec![0; cols + 1]; rows + 1];
+        let mut outside = vec![0; cols];
+        for row in 0..rows {
+            let mut inside = false;
+            for col in (0..cols).rev() {
+                outside[col] = u32::from(!boundary[row][col] && !inside);
+                inside ^= crossings[row][col];
+            }
+
+            for col in 0..cols {
+                outside_prefix[row + 1][col + 1] = outside_prefix[row][col + 1]
+                    + outside_prefix[row + 1][col]
+                    - outside_prefix[row][col]
+                    + outside[col];
+            }
+        }
+
+        Self { xs, ys, outside_prefix }
+    }
+
+    fn x_index(&self, x: i64) -> usize {
+        index_of(&self.xs, x)
+    }
+
+    fn y_index(&self, y: i64) -> usize {
+        index_of(&self.ys, y)
+    }
+
+    // Takes inclusive ranges of doubled grid indices
+    fn all_inside_doubled(&self, col0: usize, col1: usize, row0: usize, row1: usize) -> bool {
+        let p = &self.outside_prefix;
+        p[row1 + 1][col1 + 1] + p[row0][col0] == p[row0][col1 + 1] + p[row1 + 1][col0]
+    }
+
+    // Takes compressed coordinate indices, in any order
+    fn contains_rectangle(&self, i0: usize, i1: usize, j0: usize, j1: usize) -> bool {
+        self.all_inside_doubled(
+            2 * cmp::min(i0, i1),
+            2 * cmp::max(i0, i1),
+            2 * cmp::min(j0, j1),
+            2 * cmp::max(j0, j1),
+        )
+    }
+
+    fn rectangle(&self, i0: usize, i1: usize, j0: usize, j1: usize) -> Rectangle {
+        Rectangle::from_corners(
+            Point { x: self.xs[i0], y: self.ys[j0] },
+            Point { x: self.xs[i1], y: self.ys[j1] },
+        )
+    }
+}
+
+fn keep_largest(best: &mut Option<Rectangle>, candidate: Rectangle) {
+    if best.is_none_or(|best| candidate.tile_area() > best.tile_area()) {
+        *best = Some(candidate);
+    }
+}
+
+/// Finds the rectangle with the largest tile area that fits entirely inside the polygon (boundary
+/// included), subject to the given corner constraint. Ties are broken in favor of the first
+/// rectangle found.
+///
+/// Returns `None` only if the constraint is [`CornerConstraint::Fixed`] with a point outside the
+/// polygon.
+pub fn largest_rectangle(
+    polygon: &RectilinearPolygon,
+    constraint: CornerConstraint,
+) -> Option<Rectangle> {
+    match constraint {
+        CornerConstraint::Vertices => Some(largest_with_vertex_corners(polygon)),
+        CornerConstraint::Lattice => Some(largest_with_lattice_corners(polygon)),
+        CornerConstraint::Fixed(corner) => largest_with_fixed_corner(polygon, corner),
+    }
+}
+
+fn largest_with_vertex_corners(polygon: &RectilinearPolygon) -> Rectangle {
+    let grid = CompressedPolygon::new(polygon, None);
+    let indices: Vec<_> =
+        polygon.vertices().iter().map(|&p| (grid.x_index(p.x), grid.y_index(p.y))).collect();
+
+    let mut best = None;
+    for (k, &(i0, j0)) in indices.iter().enumerate() {
+        for &(i1, j1) in &indices[k + 1..] {
+            if grid.contains_rectangle(i0, i1, j0, j1) {
+                keep_largest(&mut best, grid.rectangle(i0, i1, j0, j1));
+            }
+        }
+    }
+
+    // Polygons always have at least 4 vertices, and each edge is a valid rectangle
+    best.unwrap()
+}
+
+fn largest_with_lattice_corners(polygon: &RectilinearPolygon) -> Rectangle {
+    let grid = CompressedPolygon::new(polygon, None);
+    let rows = 2 * grid.ys.len() - 1;
+
+    // For each pair of X coordinates, sweep upwards through the rows tracking the run of rows
+    // where the entire strip between the two X coordinates is inside. Runs always start and end
+    // on coordinate lines because the polygon is closed
+    let mut best = None;
+    for i0 in 0..grid.xs.len() {
+        for i1 in i0..grid.xs.len() {
+            let mut run_start = None;
+            for row in 0..rows {
+                if !grid.all_inside_doubled(2 * i0, 2 * i1, row, row) {
+                    run_start = None;
+                    continue;
+                }
+
+                if row.is_multiple_of(2) {
+                    let j0 = *run_start.get_or_insert(row / 2);
+                    keep_largest(&mut best, grid.rectangle(i0, i1, j0, row / 2));
+                }
+            }
+        }
+    }
+
+    best.unwrap()
+}
+
+fn largest_with_fixed_corner(polygon: &RectilinearPolygon, corner: Point) -> Option<Rectangle> {
+    if !polygon.contains(corner) {
+        return None;
+    }
+
+    let grid = CompressedPolygon::new(polygon, Some(corner));
+    let (i0, j0) = (grid.x_index(corner.x), grid.y_index(corner.y));
+
+    let mut best = None;
+    for i1 in 0..grid.xs.len() {
+        for j1 in 0..grid.ys.len() {
+            if grid.contains_rectangle(i0, i1, j0, j1) {
+                keep_largest(&mut best, grid.rectangle(i0, i1, j0, j1));
+            }
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polygon::Location;
+
+    fn p(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    // Day 9 sample input
+    fn sample_polygon() -> RectilinearPolygon {
+        RectilinearPolygon::new(vec![
+            p(7, 1),
+            p(11, 1),
+            p(11, 7),
+            p(9, 7),
+            p(9, 5),
+            p(2, 5),
+            p(2, 3),
+            p(7, 3),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn vertex_and_lattice_corners() {
+        let polygon = sample_polygon();
+
+        let vertices = largest_rectangle(&polygon, CornerConstraint::Vertices).unwrap();
+        assert_eq!(Rectangle { min: p(2, 3), max: p(9, 5) }, vertices);
+        assert_eq!(24, vertices.tile_area());
+
+        let lattice = largest_rectangle(&polygon, CornerConstraint::Lattice).unwrap();
+        assert_eq!(Rectangle { min: p(2, 3), max: p(11, 5) }, lattice);
+        assert_eq!(30, lattice.tile_area());
+    }
+
+    #[test]
+    fn classifies_elements_like_locate() {
+        let polygon = sample_polygon();
+        let grid = CompressedPolygon::new(&polygon, Some(p(4, 1)));
+        let doubled = |coords: &[i64], i: usize| coords[i / 2] + coords[i.div_ceil(2)];
+
+        for row in 0..2 * grid.ys.len() - 1 {
+            for col in 0..2 * grid.xs.len() - 1 {
+                let location =
+                    polygon.locate_doubled(doubled(&grid.xs, col), doubled(&grid.ys, row));
+                let outside = !grid.all_inside_doubled(col, col, row, row);
+                assert_eq!(location == Location::Outside, outside, "row {row}, column {col}");
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_corner() {
+        let polygon = sample_polygon();
+
+        let rectangle = largest_rectangle(&polygon, CornerConstraint::Fixed(p(7, 1))).unwrap();
+        assert_eq!(Rectangle { min: p(7, 1), max: p(11, 5) }, rectangle);
+
+        let rectangle = largest_rectangle(&polygon, CornerConstraint::Fixed(p(10, 4))).unwrap();
+        assert_eq!(Rectangle { min: p(2, 3), max: p(10, 4) }, rectangle);
+
+        assert_eq!(None, largest_rectangle(&polygon, CornerConstraint::Fixed(p(3, 1))));
+    }
+}
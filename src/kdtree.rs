@@ -0,0 +1,240 @@
+//! k-d tree over 3D integer points, supporting k-nearest-neighbor and radius queries
+
+use crate::Point3D;
+use std::collections::BinaryHeap;
+
+type Point = Point3D<i64>;
+
+const DIMENSIONS: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Neighbor {
+    pub distance_squared: i64,
+    pub idx: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct KdTree {
+    points: Vec<Point>,
+    // Point indices arranged so that every subtree is a contiguous slice with its root at the
+    // midpoint of the slice
+    order: Vec<usize>,
+}
+
+pub fn distance_squared(a: Point, b: Point) -> i64 {
+    let delta = a - b;
+    delta.x * delta.x + delta.y * delta.y + delta.z * delta.z
+}
+
+fn axis_value(point: Point, depth: usize) -> i64 {
+    match depth % DIMENSIONS {
+        0 => point.x,
+        1 => point.y,
+        _ => point.z,
+    }
+}
+
+fn build(points: &[Point], order: &mut [usize], depth: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+
+    let mid = order.len() / 2;
+    order.select_nth_unstable_by_key(mid, |&idx| axis_value(points[idx], depth));
+
+    let (left, right) = order.split_at_mut(mid);
+    build(points, left, depth + 1);
+    build(points, &mut right[1..], depth + 1);
+}
+
+impl KdTree {
+    pub fn new(points: &[Point]) -> Self {
+        let mut order: Vec<_> = (0..points.len()).collect();
+        build(points, &mut order, 0);
+
+        Self { points: points.to_vec(), order }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// Returns the k points closest to the target, sorted by distance and then by index.
+    pub fn k_nearest(&self, target: Point, k: usize) -> Vec<Neighbor> {
+        self.k_nearest_filtered(target, k, |_| true)
+    }
+
+    /// Same as [`Self::k_nearest`], but only considers neighbors accepted by the filter.
+    ///
+    /// Neighbors are totally ordered by (distance, index), so the result is well-defined even when
+    /// multiple points are the same distance from the target.
+    pub fn k_nearest_filtered(
+        &self,
+        target: Point,
+        k: usize,
+        filter: impl Fn(Neighbor) -> bool,
+    ) -> Vec<Neighbor> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        self.search_nearest(&self.order, 0, target, k, &filter, &mut heap);
+
+        heap.into_sorted_vec()
+    }
+
+    fn search_nearest(
+        &self,
+        order: &[usize],
+        depth: usize,
+        target: Point,
+        k: usize,
+        filter: &impl Fn(Neighbor) -> bool,
+        heap: &mut BinaryHeap<Neighbor>,
+    ) {
+        if order.is_empty() {
+            return;
+        }
+
+        let mid = order.len() / 2;
+        let idx = order[mid];
+        let point = self.points[idx];
+
+        let neighbor = Neighbor { distance_squared: distance_squared(point, target), idx };
+        if filter(neighbor) {
+            if heap.len() < k {
+                heap.push(neighbor);
+            } else if neighbor < *heap.peek().unwrap() {
+                heap.pop();
+                heap.push(neighbor);
+            }
+        }
+
+        let diff = axis_value(target, depth) - axis_value(point, depth);
+        let (near, far) = if diff < 0 {
+            (&order[..mid], &order[mid + 1..])
+        } else {
+            (&order[mid + 1..], &order[..mid])
+        };
+
+        self.search_nearest(near, depth + 1, target, k, filter, heap);
+
+        // Points on the far side can't be closer than the splitting plane. Ties must still be
+        // visited since a point at the same distance may have a lower index
+        if heap.len() < k || diff * diff <= heap.peek().unwrap().distance_squared {
+            self.search_nearest(far, depth + 1, target, k, filter, heap);
+        }
+    }
+
+    /// Returns every point within the given squared distance of the target (inclusive), sorted by
+    /// distance and then by index.
+    pub fn within_radius_squared(&self, target: Point, radius_squared: i64) -> Vec<Neighbor> {
+        let mut neighbors = Vec::new();
+        self.search_radius(&self.order, 0, target, radius_squared, &mut neighbors);
+
+        neighbors.sort();
+        neighbors
+    }
+
+    fn search_radius(
+        &self,
+        order: &[usize],
+        depth: usize,
+        target: Point,
+        radius_squared: i64,
+        neighbors: &mut Vec<Neighbor>,
+    ) {
+        if order.is_empty() {
+            return;
+        }
+
+        let mid = order.len() / 2;
+        let idx = order[mid];
+        let point = self.points[idx];
+
+        let distance_squared = distance_squared(point, target);
+        if distance_squared <= radius_squared {
+            neighbors.push(Neighbor { distance_squared, idx });
+        }
+
+        let diff = axis_value(target, depth) - axis_value(point, depth);
+        let (near, far) = if diff < 0 {
+            (&order[..mid], &order[mid + 1..])
+        } else {
+            (&order[mid + 1..], &order[..mid])
+        };
+
+        self.search_radius(near, depth + 1, target, radius_squared, neighbors);
+        if diff * diff <= radius_squared {
+            self.search_radius(far, depth + 1, target, radius_squared, neighbors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    fn test_points() -> Vec<Point> {
+        // Small coordinate range so that there are plenty of duplicate distances
+        let mut rng = Rng::new(12345);
+        let mut next = || rng.below(20) as i64;
+
+        (0..300).map(|_| Point { x: next(), y: next(), z: next() }).collect()
+    }
+
+    fn brute_force(points: &[Point], target: Point) -> Vec<Neighbor> {
+        let mut neighbors: Vec<_> = points
+            .iter()
+            .enumerate()
+            .map(|(idx, &point)| Neighbor {
+                distance_squared: distance_squared(point, target),
+                idx,
+            })
+            .collect();
+        neighbors.sort();
+        neighbors
+    }
+
+    #[test]
+    fn k_nearest() {
+        let points = test_points();
+        let tree = KdTree::new(&points);
+
+        for &target in points.iter().step_by(7) {
+            let expected = brute_force(&points, target);
+            for k in [1, 5, 17, 300, 400] {
+                let expected = &expected[..k.min(points.len())];
+                assert_eq!(expected, tree.k_nearest(target, k));
+            }
+        }
+    }
+
+    #[test]
+    fn within_radius() {
+        let points = test_points();
+        let tree = KdTree::new(&points);
+
+        for &target in points.iter().step_by(11) {
+            let expected = brute_force(&points, target);
+            for radius_squared in [0, 4, 30, 200] {
+                let expected: Vec<_> = expected
+                    .iter()
+                    .copied()
+                    .filter(|neighbor| neighbor.distance_squared <= radius_squared)
+                    .collect();
+                assert_eq!(expected, tree.within_radius_squared(target, radius_squared));
+            }
+        }
+    }
+}
@@ -1,14 +1,107 @@
+pub mod automaton;
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod differential;
+pub mod examples;
+pub mod fetch;
+pub mod generate;
+pub mod graph;
+pub mod graph_export;
+pub mod inscribed_rectangle;
+pub mod kdtree;
+pub mod mst;
+pub mod params;
+pub mod paths;
+pub mod polygon;
+pub mod puzzle_page;
+pub mod rng;
+pub mod scaffold;
+pub mod union_find;
+
+use params::{ParamSpec, Params};
 use std::error::Error;
 use std::fmt::Display;
 use std::ops::{Add, AddAssign, Index, IndexMut, Sub, SubAssign};
+use std::path::Path;
 use std::time::Instant;
 use std::{env, fs, hint, io};
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Args {
+    input_path: String,
+    params: Vec<String>,
+}
+
+// Expects an input path, plus any number of `--param name=value` or `--param=name=value` args
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut input_path = None;
+    let mut params = Vec::new();
+
+    while let Some(arg) = args.next() {
+        if arg == "--param" {
+            params.push(args.next().ok_or("Missing value after --param")?);
+        } else if let Some(assignment) = arg.strip_prefix("--param=") {
+            params.push(assignment.into());
+        } else if input_path.is_none() {
+            input_path = Some(arg);
+        } else {
+            return Err(format!("Unexpected argument '{arg}'"));
+        }
+    }
+
+    let input_path = input_path.ok_or("Missing input path arg")?;
+    Ok(Args { input_path, params })
+}
+
+fn args() -> Args {
+    parse_args(env::args().skip(1)).unwrap_or_else(|err| panic!("{err}"))
+}
+
 pub fn read_input() -> io::Result<String> {
-    let mut args = env::args();
-    args.next();
-    let path = args.next().expect("Missing input path arg");
-    fs::read_to_string(path)
+    fs::read_to_string(args().input_path)
+}
+
+/// Reads parameter overrides from the input's sidecar file and the command line, and checks them
+/// against the declared parameters.
+pub fn read_params(declared: &[&dyn ParamSpec]) -> Result<Params, Box<dyn Error>> {
+    let args = args();
+    let mut params = read_sidecar_params(Path::new(&args.input_path))?;
+
+    for assignment in &args.params {
+        params.parse_assignment(assignment)?;
+    }
+
+    params.validate(declared)?;
+    Ok(params)
+}
+
+/// Reads the parameter overrides from the input's sidecar file, if it has one.
+pub fn read_sidecar_params(input_path: &Path) -> Result<Params, Box<dyn Error>> {
+    let mut params = Params::new();
+
+    let sidecar_path = input_path.with_extension("params");
+    match fs::read_to_string(&sidecar_path) {
+        Ok(contents) => params.parse_sidecar(&contents)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    Ok(params)
+}
+
+/// Returns whether the given environment variable is set to a non-empty value.
+pub fn env_flag(name: &str) -> bool {
+    env::var(name).is_ok_and(|var| !var.is_empty())
 }
 
 fn time<T>(input: &str, f: impl Fn(&str) -> T) {
@@ -32,25 +125,67 @@ where
     F1: Fn(&str) -> T1,
     F2: Fn(&str) -> T2,
 {
+    run_with_params(&[], |input, _| part1(input), |input, _| part2(input))
+}
+
+/// Like [`run`], but the parts also receive the values of the declared parameters.
+pub fn run_with_params<T1, T2, F1, F2>(
+    declared: &[&dyn ParamSpec],
+    part1: F1,
+    part2: F2,
+) -> Result<(), Box<dyn Error>>
+where
+    T1: Display,
+    T2: Display,
+    F1: Fn(&str, &Params) -> T1,
+    F2: Fn(&str, &Params) -> T2,
+{
+    let params = read_params(declared)?;
     let input = read_input()?;
 
-    let solution1 = part1(&input);
+    let solution1 = part1(&input, &params);
     println!("{solution1}");
 
-    let solution2 = part2(&input);
+    let solution2 = part2(&input, &params);
     println!("{solution2}");
 
-    if env::var("AOCTIME").is_ok_and(|var| !var.is_empty()) {
+    if env_flag("AOCTIME") {
         print!("Part 1: ");
-        time(&input, part1);
+        time(&input, |input| part1(input, &params));
 
         print!("Part 2: ");
-        time(&input, part2);
+        time(&input, |input| part2(input, &params));
     }
 
     Ok(())
 }
 
+/// A day's solver behind a signature shared by every day, for tools that work with any day. Each
+/// day module defines one as `SOLUTION`.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub day: usize,
+    pub params: &'static [&'static dyn ParamSpec],
+    pub part1: fn(&str, &Params) -> String,
+    pub part2: fn(&str, &Params) -> String,
+}
+
+/// Every day's solution, in order of day.
+pub const SOLUTIONS: &[Solution] = &[
+    day1::SOLUTION,
+    day2::SOLUTION,
+    day3::SOLUTION,
+    day4::SOLUTION,
+    day5::SOLUTION,
+    day6::SOLUTION,
+    day7::SOLUTION,
+    day8::SOLUTION,
+    day9::SOLUTION,
+    day10::SOLUTION,
+    day11::SOLUTION,
+    day12::SOLUTION,
+];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Point2D<T> {
     pub x: T,
@@ -126,7 +261,7 @@ impl<T: SubAssign> SubAssign for Point3D<T> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid2D<T>(pub Vec<Vec<T>>);
 
 impl<T> Grid2D<T> {
@@ -159,3 +294,33 @@ macro_rules! impl_grid_index {
 
 impl_grid_index!(usize);
 impl_grid_index!(i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|&arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_param_args() {
+        assert_eq!(
+            Ok(Args {
+                input_path: "sample.txt".into(),
+                params: vec!["connections=10".into(), "threshold=3".into()],
+            }),
+            args(&["--param", "connections=10", "sample.txt", "--param=threshold=3"])
+        );
+
+        assert_eq!(Err("Missing input path arg".into()), args(&["--param", "a=1"]));
+        assert_eq!(Err("Missing value after --param".into()), args(&["input.txt", "--param"]));
+        assert_eq!(Err("Unexpected argument 'b.txt'".into()), args(&["a.txt", "b.txt"]));
+    }
+
+    #[test]
+    fn solutions_in_order() {
+        let days: Vec<_> = SOLUTIONS.iter().map(|solution| solution.day).collect();
+        assert_eq!((1..=SOLUTIONS.len()).collect::<Vec<_>>(), days);
+    }
+}
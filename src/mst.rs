@@ -0,0 +1,304 @@
+//! Minimum spanning trees and single-linkage clustering over point sets
+//!
+//! Metrics are plain functions from a pair of points to an ordered weight, e.g.
+//! [`kdtree::distance_squared`](crate::kdtree::distance_squared). Squared Euclidean distance
+//! produces the same tree as Euclidean distance without needing floating point, though the total
+//! weight is then a sum of squared distances.
+
+use crate::union_find::UnionFind;
+use std::fmt::{self, Display};
+use std::ops::Add;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Edge<W> {
+    pub weight: W,
+    pub a: usize,
+    pub b: usize,
+}
+
+/// A minimum spanning forest. Edges are sorted by (weight, a, b) regardless of which algorithm
+/// produced the tree.
+#[derive(Debug, Clone)]
+pub struct SpanningTree<W> {
+    len: usize,
+    edges: Vec<Edge<W>>,
+}
+
+/// Builds a minimum spanning tree from edges that are already sorted in increasing weight order.
+///
+/// Stops consuming edges as soon as the tree is connected, so this works with lazily generated
+/// edge iterators. If the edges never connect every vertex, the result is a spanning forest.
+pub fn kruskal<W: Copy>(
+    len: usize,
+    sorted_edges: impl IntoIterator<Item = Edge<W>>,
+) -> SpanningTree<W> {
+    let mut components = UnionFind::new(len);
+    let mut edges = Vec::with_capacity(len.saturating_sub(1));
+    if len <= 1 {
+        return SpanningTree { len, edges };
+    }
+
+    for edge in sorted_edges {
+        if components.union(edge.a, edge.b) {
+            edges.push(edge);
+            if edges.len() == len - 1 {
+                break;
+            }
+        }
+    }
+
+    SpanningTree { len, edges }
+}
+
+/// Kruskal's algorithm over the complete graph of the given points. This materializes and sorts
+/// every pair, so prefer [`prim_points`] for large point sets.
+pub fn kruskal_points<P, W>(points: &[P], metric: impl Fn(P, P) -> W) -> SpanningTree<W>
+where
+    P: Copy,
+    W: Copy + Ord,
+{
+    let mut pairs = Vec::with_capacity(points.len() * points.len().saturating_sub(1) / 2);
+    for (a, &point) in points.iter().enumerate() {
+        for (b, &other_point) in points.iter().enumerate().skip(a + 1) {
+            pairs.push(Edge { weight: metric(point, other_point), a, b });
+        }
+    }
+
+    pairs.sort();
+    kruskal(points.len(), pairs)
+}
+
+/// Dense Prim's algorithm over the complete graph of the given points. Runs in O(n²) time and
+/// O(n) memory, computing distances on the fly instead of building a pair list.
+pub fn prim_points<P, W>(points: &[P], metric: impl Fn(P, P) -> W) -> SpanningTree<W>
+where
+    P: Copy,
+    W: Copy + Ord,
+{
+    let len = points.len();
+    if len == 0 {
+        return SpanningTree { len, edges: Vec::new() };
+    }
+
+    // For each vertex not yet in the tree, the cheapest edge connecting it to the tree
+    let mut in_tree = vec![false; len];
+    let mut cheapest: Vec<Option<Edge<W>>> = vec![None; len];
+    let mut edges = Vec::with_capacity(len - 1);
+
+    let mut current = 0;
+    in_tree[0] = true;
+    for _ in 1..len {
+        let mut next: Option<Edge<W>> = None;
+
+        for (i, &point) in points.iter().enumerate() {
+            if in_tree[i] {
+                continue;
+            }
+
+            let weight = metric(points[current], point);
+            let candidate = Edge { weight, a: current.min(i), b: current.max(i) };
+            if cheapest[i].is_none_or(|edge| candidate < edge) {
+                cheapest[i] = Some(candidate);
+            }
+
+            let edge = cheapest[i].unwrap();
+            if next.is_none_or(|next| edge < next) {
+                next = Some(edge);
+            }
+        }
+
+        let edge = next.unwrap();
+        current = if in_tree[edge.a] { edge.b } else { edge.a };
+        in_tree[current] = true;
+        edges.push(edge);
+    }
+
+    edges.sort();
+    SpanningTree { len, edges }
+}
+
+impl<W: Copy> SpanningTree<W> {
+    /// Number of vertices
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn edges(&self) -> &[Edge<W>] {
+        &self.edges
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.edges.len() + 1 >= self.len
+    }
+
+    pub fn total_weight(&self) -> W
+    where
+        W: Add<Output = W> + Default,
+    {
+        self.edges.iter().fold(W::default(), |total, edge| total + edge.weight)
+    }
+
+    /// The edge whose addition connected every vertex, i.e. the heaviest edge in the tree.
+    /// Returns `None` if the tree is not connected or has no edges.
+    pub fn completing_edge(&self) -> Option<Edge<W>> {
+        if self.is_connected() { self.edges.last().copied() } else { None }
+    }
+
+    fn components_after(&self, num_edges: usize) -> UnionFind {
+        let mut components = UnionFind::new(self.len);
+        for edge in &self.edges[..num_edges] {
+            components.union(edge.a, edge.b);
+        }
+
+        components
+    }
+
+    fn edges_for_clusters(&self, k: usize) -> usize {
+        // Each edge merges two clusters; a forest may not be able to get down to k
+        self.len.saturating_sub(k.max(1)).min(self.edges.len())
+    }
+
+    /// Single-linkage clustering into (at most) k clusters, returned as a cluster label per vertex.
+    /// Labels are numbered in order of each cluster's first vertex.
+    pub fn clusters(&self, k: usize) -> Vec<usize> {
+        let mut components = self.components_after(self.edges_for_clusters(k));
+
+        let mut root_labels = vec![None; self.len];
+        let mut next_label = 0;
+        (0..self.len)
+            .map(|i| {
+                let root = components.find(i);
+                *root_labels[root].get_or_insert_with(|| {
+                    next_label += 1;
+                    next_label - 1
+                })
+            })
+            .collect()
+    }
+
+    /// Sizes of the single-linkage clusters at k, sorted from largest to smallest.
+    pub fn cluster_sizes(&self, k: usize) -> Vec<usize> {
+        let components = self.components_after(self.edges_for_clusters(k));
+
+        let mut sizes = components.component_sizes();
+        sizes.sort_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    /// The full merge history of single-linkage clustering.
+    pub fn dendrogram(&self) -> Dendrogram<W> {
+        let mut components = UnionFind::new(self.len);
+        let mut cluster_ids: Vec<_> = (0..self.len).collect();
+
+        let merges = self
+            .edges
+            .iter()
+            .enumerate()
+            .map(|(step, edge)| {
+                let left = cluster_ids[components.find(edge.a)];
+                let right = cluster_ids[components.find(edge.b)];
+
+                components.union(edge.a, edge.b);
+                let root = components.find(edge.a);
+                cluster_ids[root] = self.len + step;
+
+                Merge {
+                    left: left.min(right),
+                    right: left.max(right),
+                    weight: edge.weight,
+                    size: components.size_of(root),
+                }
+            })
+            .collect();
+
+        Dendrogram(merges)
+    }
+}
+
+/// A single merge in a dendrogram. Vertices are clusters `0..n`, and the cluster created by merge
+/// `i` has id `n + i`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Merge<W> {
+    pub left: usize,
+    pub right: usize,
+    pub weight: W,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dendrogram<W>(pub Vec<Merge<W>>);
+
+// One merge per line as "left right weight size", the same layout as a SciPy linkage matrix
+impl<W: Display> Display for Dendrogram<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for merge in &self.0 {
+            writeln!(f, "{} {} {} {}", merge.left, merge.right, merge.weight, merge.size)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn manhattan(a: (i64, i64), b: (i64, i64)) -> i64 {
+        (a.0 - b.0).abs() + (a.1 - b.1).abs()
+    }
+
+    fn test_points() -> Vec<(i64, i64)> {
+        vec![(0, 0), (1, 0), (5, 5), (6, 5), (6, 7), (20, 0), (0, 2)]
+    }
+
+    #[test]
+    fn kruskal_matches_prim() {
+        let points = test_points();
+        let kruskal = kruskal_points(&points, manhattan);
+        let prim = prim_points(&points, manhattan);
+
+        assert_eq!(kruskal.edges(), prim.edges());
+        assert_eq!(33, kruskal.total_weight());
+        assert_eq!(Some(Edge { weight: 19, a: 1, b: 5 }), kruskal.completing_edge());
+    }
+
+    #[test]
+    fn kruskal_stops_when_connected() {
+        // The fourth edge connects the tree, so the last edge is never generated
+        let pulled = Cell::new(0);
+        let edges = || {
+            [(1, 0, 1), (2, 1, 2), (3, 0, 2), (4, 2, 3), (5, 0, 3)]
+                .into_iter()
+                .map(|(weight, a, b)| Edge { weight, a, b })
+                .inspect(|_| pulled.set(pulled.get() + 1))
+        };
+
+        let tree = kruskal(4, edges());
+        assert_eq!(Some(Edge { weight: 4, a: 2, b: 3 }), tree.completing_edge());
+        assert_eq!(4, pulled.get());
+
+        pulled.set(0);
+        assert!(kruskal(1, edges()).edges().is_empty());
+        assert_eq!(0, pulled.get());
+    }
+
+    #[test]
+    fn clustering() {
+        let tree = prim_points(&test_points(), manhattan);
+
+        assert_eq!(vec![0, 0, 1, 1, 1, 2, 0], tree.clusters(3));
+        assert_eq!(vec![3, 3, 1], tree.cluster_sizes(3));
+        assert_eq!(vec![7], tree.cluster_sizes(1));
+        assert_eq!(vec![1; 7], tree.cluster_sizes(10));
+
+        let dendrogram = tree.dendrogram();
+        assert_eq!(Merge { left: 0, right: 1, weight: 1, size: 2 }, dendrogram.0[0]);
+        assert_eq!(Merge { left: 9, right: 10, weight: 8, size: 6 }, dendrogram.0[4]);
+        assert_eq!("0 1 1 2\n", &dendrogram.to_string()[..8]);
+    }
+}
@@ -0,0 +1,169 @@
+//! Named puzzle parameters, for constants that differ between the sample and real inputs
+//!
+//! Each day declares its parameters as [`Param`] constants with default values for the real
+//! input. Defaults can be overridden by a sidecar file next to the input, with the same name as
+//! the input file but a `.params` extension, and by `--param name=value` command-line arguments,
+//! which take precedence over the sidecar file. Sidecar files contain one `name = value` pair per
+//! line; blank lines and lines starting with `#` are ignored.
+
+use rustc_hash::FxHashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Param<T> {
+    pub name: &'static str,
+    pub default: T,
+}
+
+impl<T> Param<T> {
+    pub const fn new(name: &'static str, default: T) -> Self {
+        Self { name, default }
+    }
+}
+
+/// Type-erased view of a [`Param`], used to check overrides before any part runs.
+pub trait ParamSpec {
+    fn name(&self) -> &'static str;
+
+    fn validate(&self, value: &str) -> Result<(), ParamError>;
+}
+
+impl<T> ParamSpec for Param<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn validate(&self, value: &str) -> Result<(), ParamError> {
+        value.parse::<T>().map(|_| ()).map_err(|err| ParamError::Invalid {
+            name: self.name.into(),
+            value: value.into(),
+            reason: err.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    Malformed(String),
+    Unknown(String),
+    Invalid { name: String, value: String, reason: String },
+}
+
+impl Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed(s) => write!(f, "Expected a parameter as 'name=value', got '{s}'"),
+            Self::Unknown(name) => write!(f, "Unknown parameter '{name}'"),
+            Self::Invalid { name, value, reason } => {
+                write!(f, "Invalid value '{value}' for parameter '{name}': {reason}")
+            }
+        }
+    }
+}
+
+impl Error for ParamError {}
+
+/// Parameter overrides, keyed by name.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    overrides: FxHashMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an override from a `name=value` string, replacing any earlier override of the same
+    /// parameter.
+    pub fn parse_assignment(&mut self, assignment: &str) -> Result<(), ParamError> {
+        let (name, value) = assignment
+            .split_once('=')
+            .map(|(name, value)| (name.trim(), value.trim()))
+            .filter(|(name, _)| !name.is_empty())
+            .ok_or_else(|| ParamError::Malformed(assignment.into()))?;
+
+        self.overrides.insert(name.into(), value.into());
+        Ok(())
+    }
+
+    /// Adds the overrides from the contents of a sidecar file.
+    pub fn parse_sidecar(&mut self, contents: &str) -> Result<(), ParamError> {
+        contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .try_for_each(|line| self.parse_assignment(line))
+    }
+
+    /// Checks that every override names a declared parameter and has a valid value.
+    pub fn validate(&self, declared: &[&dyn ParamSpec]) -> Result<(), ParamError> {
+        for (name, value) in &self.overrides {
+            let spec = declared
+                .iter()
+                .find(|spec| spec.name() == name)
+                .ok_or_else(|| ParamError::Unknown(name.clone()))?;
+            spec.validate(value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the parameter's overridden value, or its default if it has no override.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the override is not a valid value, which [`Params::validate`] checks for.
+    pub fn get<T>(&self, param: &Param<T>) -> T
+    where
+        T: Clone + FromStr,
+        T::Err: Display,
+    {
+        match self.overrides.get(param.name) {
+            Some(value) => value.parse().unwrap_or_else(|err| {
+                panic!("Invalid value '{value}' for parameter '{}': {err}", param.name)
+            }),
+            None => param.default.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONNECTIONS: Param<usize> = Param::new("connections", 1000);
+
+    #[test]
+    fn overrides() {
+        let mut params = Params::new();
+        assert_eq!(1000, params.get(&CONNECTIONS));
+
+        params.parse_sidecar("# Sample input\n\nconnections = 10\nlabel=sample\n").unwrap();
+        params.parse_assignment("connections=20").unwrap();
+        assert_eq!(20, params.get(&CONNECTIONS));
+
+        assert_eq!(Err(ParamError::Unknown("label".into())), params.validate(&[&CONNECTIONS]));
+        assert_eq!(Ok(()), Params::new().validate(&[&CONNECTIONS]));
+    }
+
+    #[test]
+    fn invalid() {
+        let mut params = Params::new();
+        assert_eq!(Err(ParamError::Malformed("10".into())), params.parse_assignment("10"));
+        assert_eq!(Err(ParamError::Malformed("=10".into())), params.parse_assignment("=10"));
+
+        params.parse_assignment("connections=ten").unwrap();
+        let err = params.validate(&[&CONNECTIONS]).unwrap_err();
+        assert_eq!(
+            "Invalid value 'ten' for parameter 'connections': invalid digit found in string",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! Counting paths through a directed acyclic graph, optionally requiring that paths visit a set of
+//! waypoints and avoid a set of forbidden nodes
+//!
+//! The graph is first pruned to nodes that lie on some source-to-sink path avoiding the forbidden
+//! nodes, which is also the only part of the graph that must be acyclic (ignoring edges out of the
+//! sink, where paths end). Counts are then propagated forwards in topological order, tracking
+//! which required waypoints each partial path has visited as a bitmask. Counts use checked 128-bit
+//! arithmetic since path counts grow exponentially with graph depth.
+
+use crate::graph::{CycleError, Graph};
+use rustc_hash::FxHashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::mem;
+
+pub const MAX_REQUIRED: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathQuery<'a> {
+    pub source: &'a str,
+    pub sink: &'a str,
+    pub required: Vec<&'a str>,
+    pub forbidden: Vec<&'a str>,
+}
+
+impl<'a> PathQuery<'a> {
+    pub fn new(source: &'a str, sink: &'a str) -> Self {
+        Self { source, sink, required: Vec::new(), forbidden: Vec::new() }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathCountError {
+    Overflow,
+    TooManyRequired(usize),
+    UnknownNode(String),
+    Cycle(CycleError),
+}
+
+impl Display for PathCountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "Path count does not fit in 128 bits"),
+            Self::TooManyRequired(len) => {
+                write!(f, "At most {MAX_REQUIRED} required nodes are supported, got {len}")
+            }
+            Self::UnknownNode(name) => write!(f, "Node '{name}' is not in the graph"),
+            Self::Cycle(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for PathCountError {}
+
+impl From<CycleError> for PathCountError {
+    fn from(value: CycleError) -> Self {
+        Self::Cycle(value)
+    }
+}
+
+/// Per-node path counts from the source, keyed by the set of required nodes visited so far.
+///
+/// Only nodes on some valid source-to-sink path have entries.
+#[derive(Debug, Clone)]
+pub struct PathCounts {
+    counts: Vec<FxHashMap<u64, u128>>,
+    all_required: u64,
+}
+
+impl PathCounts {
+    /// Number of paths from the source to this node, regardless of which waypoints they visited.
+    pub fn to_node(&self, id: usize) -> Result<u128, PathCountError> {
+        self.counts[id]
+            .values()
+            .try_fold(0_u128, |sum, &count| sum.checked_add(count))
+            .ok_or(PathCountError::Overflow)
+    }
+
+    /// Number of paths from the source to this node that have visited every required waypoint.
+    pub fn complete_to_node(&self, id: usize) -> u128 {
+        self.counts[id].get(&self.all_required).copied().unwrap_or(0)
+    }
+}
+
+fn node_id(graph: &Graph<'_>, name: &str) -> Result<usize, PathCountError> {
+    graph.id(name).ok_or_else(|| PathCountError::UnknownNode(name.into()))
+}
+
+/// Counts the distinct paths from the query's source to its sink that pass through every required
+/// node and no forbidden nodes.
+pub fn count_paths(graph: &Graph<'_>, query: &PathQuery<'_>) -> Result<u128, PathCountError> {
+    let sink = node_id(graph, query.sink)?;
+    let counts = path_counts(graph, query)?;
+    Ok(counts.complete_to_node(sink))
+}
+
+/// Computes path counts from the source for every node on a valid source-to-sink path.
+pub fn path_counts(graph: &Graph<'_>, query: &PathQuery<'_>) -> Result<PathCounts, PathCountError> {
+    if query.required.len() > MAX_REQUIRED {
+        return Err(PathCountError::TooManyRequired(query.required.len()));
+    }
+
+    let source = node_id(graph, query.source)?;
+    let sink = node_id(graph, query.sink)?;
+
+    let mut required_bits = vec![0_u64; graph.len()];
+    for (i, &name) in query.required.iter().enumerate() {
+        required_bits[node_id(graph, name)?] |= 1 << i;
+    }
+
+    let all_required = match query.required.len() {
+        0 => 0,
+        len => u64::MAX >> (MAX_REQUIRED - len),
+    };
+
+    let mut allowed = vec![true; graph.len()];
+    for &name in &query.forbidden {
+        if let Some(id) = graph.id(name) {
+            allowed[id] = false;
+        }
+    }
+
+    let relevant = graph.between(source, sink, &allowed);
+
+    // Paths end at the sink, so its outgoing edges are never followed and mustn't count towards
+    // cycles. Leaving it out of the order drops those edges, and nothing is propagated from it
+    let mut before_sink = relevant.clone();
+    before_sink[sink] = false;
+    let order = graph.topological_order_within(&before_sink)?;
+
+    let mut counts = vec![FxHashMap::default(); graph.len()];
+    if relevant[source] {
+        counts[source].insert(required_bits[source], 1);
+    }
+
+    for id in order {
+        let node_counts = mem::take(&mut counts[id]);
+        for &next in graph.successors(id) {
+            if !relevant[next] {
+                continue;
+            }
+
+            for (&visited, &count) in &node_counts {
+                let entry: &mut u128 =
+                    counts[next].entry(visited | required_bits[next]).or_insert(0);
+                *entry = entry.checked_add(count).ok_or(PathCountError::Overflow)?;
+            }
+        }
+        counts[id] = node_counts;
+    }
+
+    Ok(PathCounts { counts, all_required })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diamond_chain(len: usize) -> Vec<(String, Vec<String>)> {
+        // Each diamond doubles the number of paths
+        (0..len)
+            .flat_map(|i| {
+                [
+                    (format!("n{i}"), vec![format!("a{i}"), format!("b{i}")]),
+                    (format!("a{i}"), vec![format!("n{}", i + 1)]),
+                    (format!("b{i}"), vec![format!("n{}", i + 1)]),
+                ]
+            })
+            .collect()
+    }
+
+    fn build_graph(nodes: &[(String, Vec<String>)]) -> Graph<'_> {
+        Graph::from_adjacency(
+            nodes.iter().map(|(name, edges)| (name.as_str(), edges.iter().map(String::as_str))),
+        )
+    }
+
+    #[test]
+    fn required_and_forbidden() {
+        let nodes = diamond_chain(10);
+        let graph = build_graph(&nodes);
+
+        assert_eq!(Ok(1024), count_paths(&graph, &PathQuery::new("n0", "n10")));
+
+        let query = PathQuery { required: vec!["a3", "b7"], ..PathQuery::new("n0", "n10") };
+        assert_eq!(Ok(256), count_paths(&graph, &query));
+
+        let query = PathQuery { forbidden: vec!["a0", "a1"], ..PathQuery::new("n0", "n10") };
+        assert_eq!(Ok(256), count_paths(&graph, &query));
+
+        let query = PathQuery { required: vec!["a0", "b0"], ..PathQuery::new("n0", "n10") };
+        assert_eq!(Ok(0), count_paths(&graph, &query));
+    }
+
+    #[test]
+    fn overflow() {
+        let nodes = diamond_chain(127);
+        let graph = build_graph(&nodes);
+        assert_eq!(Ok(1 << 127), count_paths(&graph, &PathQuery::new("n0", "n127")));
+
+        let nodes = diamond_chain(128);
+        let graph = build_graph(&nodes);
+        assert_eq!(
+            Err(PathCountError::Overflow),
+            count_paths(&graph, &PathQuery::new("n0", "n128"))
+        );
+
+        // Paths through either side of the first diamond fit, but not both together
+        let query = PathQuery { required: vec!["a0"], ..PathQuery::new("n0", "n128") };
+        let counts = path_counts(&graph, &query).unwrap();
+        let sink = graph.id("n128").unwrap();
+        assert_eq!(1 << 127, counts.complete_to_node(sink));
+        assert_eq!(Err(PathCountError::Overflow), counts.to_node(sink));
+    }
+
+    #[test]
+    fn invalid_graphs() {
+        let graph = Graph::from_adjacency([
+            ("a", vec!["b", "c"]),
+            ("b", vec!["d"]),
+            ("c", vec!["e"]),
+            ("e", vec!["c", "d"]),
+        ]);
+
+        let err = count_paths(&graph, &PathQuery::new("a", "d")).unwrap_err();
+        assert_eq!(
+            PathCountError::Cycle(CycleError { cycle: vec!["c".into(), "e".into(), "c".into()] }),
+            err
+        );
+
+        // Cycles that can't be part of a path from the source to the sink are fine
+        assert_eq!(Ok(1), count_paths(&graph, &PathQuery::new("b", "d")));
+        let query = PathQuery { forbidden: vec!["c"], ..PathQuery::new("a", "d") };
+        assert_eq!(Ok(1), count_paths(&graph, &query));
+        assert_eq!(Ok(0), count_paths(&graph, &PathQuery::new("d", "a")));
+
+        // Neither are cycles through the sink, since paths stop there
+        let graph = Graph::from_adjacency([
+            ("a", vec!["b", "c"]),
+            ("b", vec!["d"]),
+            ("c", vec!["d"]),
+            ("d", vec!["b", "e"]),
+            ("e", vec!["d"]),
+        ]);
+        assert_eq!(Ok(2), count_paths(&graph, &PathQuery::new("a", "d")));
+        let err = count_paths(&graph, &PathQuery::new("a", "e")).unwrap_err();
+        assert!(matches!(err, PathCountError::Cycle(_)), "{err:?}");
+
+        let err = count_paths(&graph, &PathQuery::new("a", "z")).unwrap_err();
+        assert_eq!(PathCountError::UnknownNode("z".into()), err);
+    }
+}
@@ -0,0 +1,355 @@
+//! Simple rectilinear (axis-aligned) polygons with integer vertices
+//!
+//! Polygons are treated as closed regions, so points on the boundary count as inside. Orientation
+//! uses the standard mathematical convention with Y increasing upwards; in screen coordinates
+//! (Y increasing downwards) clockwise and counter-clockwise are swapped.
+//!
+//! Internally, point queries work on doubled coordinates so that midpoints between integer
+//! coordinates can be tested without floating point.
+
+use crate::Point2D;
+use std::cmp;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+type Point = Point2D<i64>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Corner {
+    Convex, // Interior angle is 90 degrees
+    Reflex, // Interior angle is 270 degrees
+    Straight,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolygonError {
+    TooFewVertices(usize),
+    ZeroLengthEdge { edge: usize },
+    NotAxisAligned { edge: usize },
+    SelfIntersecting { first_edge: usize, second_edge: usize },
+}
+
+impl Display for PolygonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewVertices(len) => {
+                write!(f, "Polygon must have at least 4 vertices, has {len}")
+            }
+            Self::ZeroLengthEdge { edge } => write!(f, "Edge {edge} has zero length"),
+            Self::NotAxisAligned { edge } => write!(f, "Edge {edge} is not axis-aligned"),
+            Self::SelfIntersecting { first_edge, second_edge } => {
+                write!(f, "Edges {first_edge} and {second_edge} intersect")
+            }
+        }
+    }
+}
+
+impl Error for PolygonError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Edge {
+    start: Point,
+    end: Point,
+}
+
+impl Edge {
+    fn is_vertical(self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    fn min(self) -> Point {
+        Point { x: cmp::min(self.start.x, self.end.x), y: cmp::min(self.start.y, self.end.y) }
+    }
+
+    fn max(self) -> Point {
+        Point { x: cmp::max(self.start.x, self.end.x), y: cmp::max(self.start.y, self.end.y) }
+    }
+
+    fn direction(self) -> Point {
+        Point { x: (self.end.x - self.start.x).signum(), y: (self.end.y - self.start.y).signum() }
+    }
+
+    // Axis-aligned segments intersect iff their bounding boxes do
+    fn intersects(self, other: Self) -> bool {
+        let (min, max) = (self.min(), self.max());
+        let (other_min, other_max) = (other.min(), other.max());
+
+        cmp::max(min.x, other_min.x) <= cmp::min(max.x, other_max.x)
+            && cmp::max(min.y, other_min.y) <= cmp::min(max.y, other_max.y)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RectilinearPolygon {
+    vertices: Vec<Point>,
+}
+
+impl RectilinearPolygon {
+    /// Creates a polygon from its vertices in loop order. The last vertex connects back to the
+    /// first, so the first vertex should not be repeated at the end.
+    pub fn new(vertices: Vec<Point>) -> Result<Self, PolygonError> {
+        let polygon = Self { vertices };
+        polygon.validate()?;
+        Ok(polygon)
+    }
+
+    fn validate(&self) -> Result<(), PolygonError> {
+        let len = self.vertices.len();
+        if len < 4 {
+            return Err(PolygonError::TooFewVertices(len));
+        }
+
+        let edges: Vec<_> = self.edges().collect();
+        for (i, edge) in edges.iter().enumerate() {
+            if edge.start == edge.end {
+                return Err(PolygonError::ZeroLengthEdge { edge: i });
+            }
+
+            if edge.start.x != edge.end.x && edge.start.y != edge.end.y {
+                return Err(PolygonError::NotAxisAligned { edge: i });
+            }
+        }
+
+        for (i, &edge) in edges.iter().enumerate() {
+            // Adjacent edges share a vertex, so they only intersect improperly by doubling back
+            let next = edges[(i + 1) % len];
+            let doubles_back = edge.direction() + next.direction() == Point { x: 0, y: 0 };
+            if doubles_back {
+                return Err(PolygonError::SelfIntersecting {
+                    first_edge: i,
+                    second_edge: (i + 1) % len,
+                });
+            }
+
+            let last = if i == 0 { len - 1 } else { len };
+            for (j, &other) in edges.iter().enumerate().take(last).skip(i + 2) {
+                if edge.intersects(other) {
+                    return Err(PolygonError::SelfIntersecting { first_edge: i, second_edge: j });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = Edge> + '_ {
+        (0..self.vertices.len()).map(|i| Edge {
+            start: self.vertices[i],
+            end: self.vertices[(i + 1) % self.vertices.len()],
+        })
+    }
+
+    /// Twice the signed area from the shoelace formula. Positive for counter-clockwise polygons.
+    pub fn signed_area_doubled(&self) -> i64 {
+        self.edges().map(|edge| edge.start.x * edge.end.y - edge.end.x * edge.start.y).sum()
+    }
+
+    /// Geometric area of the enclosed region. Rectilinear polygons with integer vertices always
+    /// have integer area.
+    pub fn area(&self) -> i64 {
+        self.signed_area_doubled().abs() / 2
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        if self.signed_area_doubled() > 0 {
+            Orientation::CounterClockwise
+        } else {
+            Orientation::Clockwise
+        }
+    }
+
+    /// Classifies every vertex by its interior angle, in vertex order.
+    pub fn corners(&self) -> Vec<Corner> {
+        let len = self.vertices.len();
+        let orientation_sign = match self.orientation() {
+            Orientation::CounterClockwise => 1,
+            Orientation::Clockwise => -1,
+        };
+
+        (0..len)
+            .map(|i| {
+                let prev = self.vertices[(i + len - 1) % len];
+                let point = self.vertices[i];
+                let next = self.vertices[(i + 1) % len];
+
+                let incoming = point - prev;
+                let outgoing = next - point;
+                let cross = incoming.x.signum() * outgoing.y.signum()
+                    - incoming.y.signum() * outgoing.x.signum();
+
+                match cross * orientation_sign {
+                    1 => Corner::Convex,
+                    -1 => Corner::Reflex,
+                    _ => Corner::Straight,
+                }
+            })
+            .collect()
+    }
+
+    pub(crate) fn locate_doubled(&self, x: i64, y: i64) -> Location {
+        let mut crossings = 0;
+
+        for edge in self.edges() {
+            let (min, max) = (edge.min(), edge.max());
+            if (2 * min.x..=2 * max.x).contains(&x) && (2 * min.y..=2 * max.y).contains(&y) {
+                return Location::Boundary;
+            }
+
+            // Cast a ray in the +X direction. Half-open Y ranges ensure that passing through a
+            // vertex counts exactly once when the ray actually crosses the boundary
+            if edge.is_vertical() && 2 * min.x > x && (2 * min.y..2 * max.y).contains(&y) {
+                crossings += 1;
+            }
+        }
+
+        if crossings % 2 == 1 { Location::Inside } else { Location::Outside }
+    }
+
+    pub fn locate(&self, point: Point) -> Location {
+        self.locate_doubled(2 * point.x, 2 * point.y)
+    }
+
+    /// Returns whether the point is inside the polygon or on its boundary.
+    pub fn contains(&self, point: Point) -> bool {
+        self.locate(point) != Location::Outside
+    }
+
+    /// Returns whether every point of the axis-aligned segment from `a` to `b` is inside the
+    /// polygon or on its boundary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the segment is not axis-aligned.
+    pub fn contains_segment(&self, a: Point, b: Point) -> bool {
+        assert!(a.x == b.x || a.y == b.y, "Segment {a:?} to {b:?} is not axis-aligned");
+
+        // The boundary can only cross or touch the segment at vertex coordinates, so between
+        // consecutive breakpoints the segment is either entirely inside or entirely outside
+        let horizontal = a.y == b.y;
+        let along: fn(Point) -> i64 = if horizontal { |p| p.x } else { |p| p.y };
+        let fixed = if horizontal { a.y } else { a.x };
+        let (start, end) = (cmp::min(along(a), along(b)), cmp::max(along(a), along(b)));
+
+        let mut breakpoints: Vec<_> = self
+            .vertices
+            .iter()
+            .map(|&vertex| along(vertex))
+            .filter(|&v| start < v && v < end)
+            .chain([start, end])
+            .collect();
+        breakpoints.sort_unstable();
+        breakpoints.dedup();
+
+        let locate = |doubled_along: i64| {
+            if horizontal {
+                self.locate_doubled(doubled_along, 2 * fixed)
+            } else {
+                self.locate_doubled(2 * fixed, doubled_along)
+            }
+        };
+
+        breakpoints.iter().all(|&v| locate(2 * v) != Location::Outside)
+            && breakpoints.windows(2).all(|w| locate(w[0] + w[1]) != Location::Outside)
+    }
+
+    /// Returns whether the axis-aligned rectangle with opposite corners `a` and `b` is entirely
+    /// inside the polygon, including its boundary.
+    pub fn contains_rectangle(&self, a: Point, b: Point) -> bool {
+        let min = Point { x: cmp::min(a.x, b.x), y: cmp::min(a.y, b.y) };
+        let max = Point { x: cmp::max(a.x, b.x), y: cmp::max(a.y, b.y) };
+
+        if min.x == max.x || min.y == max.y {
+            return self.contains_segment(min, max);
+        }
+
+        // If no edge passes through the open interior of the rectangle, the interior is either
+        // entirely inside or entirely outside the polygon, and the closed rectangle follows
+        let crosses_interior = self.edges().any(|edge| {
+            let (edge_min, edge_max) = (edge.min(), edge.max());
+            edge_min.x < max.x && edge_max.x > min.x && edge_min.y < max.y && edge_max.y > min.y
+        });
+
+        !crosses_interior && self.locate_doubled(min.x + max.x, min.y + max.y) == Location::Inside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(points: &[(i64, i64)]) -> Result<RectilinearPolygon, PolygonError> {
+        RectilinearPolygon::new(points.iter().map(|&(x, y)| Point { x, y }).collect())
+    }
+
+    // U shape with a 1-wide slot cut into the top, between x=3 and x=4
+    fn u_shape() -> RectilinearPolygon {
+        polygon(&[(0, 0), (7, 0), (7, 5), (4, 5), (4, 2), (3, 2), (3, 5), (0, 5)]).unwrap()
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(Some(PolygonError::TooFewVertices(2)), polygon(&[(0, 0), (1, 0)]).err());
+        assert_eq!(
+            Some(PolygonError::NotAxisAligned { edge: 1 }),
+            polygon(&[(0, 0), (2, 0), (0, 2), (0, 1)]).err()
+        );
+        assert_eq!(
+            Some(PolygonError::SelfIntersecting { first_edge: 0, second_edge: 3 }),
+            polygon(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, -2), (0, -2)]).err()
+        );
+
+        let u_shape = u_shape();
+        assert_eq!(32, u_shape.area());
+        assert_eq!(Orientation::CounterClockwise, u_shape.orientation());
+        assert_eq!(
+            vec![
+                Corner::Convex,
+                Corner::Convex,
+                Corner::Convex,
+                Corner::Convex,
+                Corner::Reflex,
+                Corner::Reflex,
+                Corner::Convex,
+                Corner::Convex
+            ],
+            u_shape.corners()
+        );
+    }
+
+    #[test]
+    fn containment() {
+        let u_shape = u_shape();
+        let p = |x, y| Point { x, y };
+
+        assert_eq!(Location::Inside, u_shape.locate(p(1, 1)));
+        assert_eq!(Location::Boundary, u_shape.locate(p(3, 4)));
+        assert_eq!(Location::Outside, u_shape.locate(p(8, 0)));
+        assert!(u_shape.contains(p(4, 2)));
+
+        assert!(u_shape.contains_segment(p(0, 2), p(7, 2)));
+        assert!(!u_shape.contains_segment(p(0, 3), p(7, 3)));
+        assert!(u_shape.contains_segment(p(3, 2), p(3, 5)));
+
+        assert!(u_shape.contains_rectangle(p(0, 0), p(7, 2)));
+        assert!(u_shape.contains_rectangle(p(7, 5), p(4, 0)));
+        assert!(!u_shape.contains_rectangle(p(0, 0), p(7, 3)));
+        assert!(!u_shape.contains_rectangle(p(2, 3), p(5, 4)));
+    }
+}
@@ -0,0 +1,214 @@
+//! Extraction of example inputs and answers from a saved puzzle page
+//!
+//! A puzzle page has one `<article>` per part, or just one until part 1 is solved. The example
+//! input is the first `<pre><code>` block in a part's description, and the example's answer is
+//! the last highlighted code in the description (`<code><em>...</em></code>`), which is how the
+//! puzzle descriptions present them. Part 2 usually reuses the part 1 example, in which case its
+//! answer goes to that example; if it has its own example block, that becomes a second example.
+//! An answer with no example to go with it is an error rather than being dropped.
+//!
+//! The extraction relies on these conventions rather than understanding the text, so the answers
+//! should be checked against the description before committing them.
+
+use crate::examples::Answers;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// An example input and the answers found for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: Answers,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtractError {
+    AnswerWithoutExample { part: usize, answer: String },
+}
+
+impl Display for ExtractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AnswerWithoutExample { part, answer } => {
+                write!(f, "Found answer '{answer}' for part {part}, but no example block for it")
+            }
+        }
+    }
+}
+
+impl Error for ExtractError {}
+
+/// Returns each substring between `start` and `end`, not including the delimiters.
+fn between<'a>(mut html: &'a str, start: &str, end: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    while let Some(i) = html.find(start) {
+        let rest = &html[i + start.len()..];
+        let Some(j) = rest.find(end) else { break };
+        found.push(&rest[..j]);
+        html = &rest[j + end.len()..];
+    }
+    found
+}
+
+/// Strips tags and decodes entities, leaving the text as it's displayed.
+fn text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(i) = rest.find('<') {
+        text.push_str(&rest[..i]);
+        rest = rest[i..].find('>').map_or("", |j| &rest[i + j + 1..]);
+    }
+    text.push_str(rest);
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn example_input(article: &str) -> Option<String> {
+    let block = between(article, "<pre><code>", "</code></pre>").into_iter().next()?;
+    let input = text(block);
+    Some(format!("{}\n", input.trim_end_matches('\n')))
+}
+
+fn answer(article: &str) -> Option<String> {
+    // Answers are highlighted as either <code><em>...</em></code> or <em><code>...</code></em>
+    let article =
+        article.replace("<em><code>", "<code><em>").replace("</code></em>", "</em></code>");
+    between(&article, "<code><em>", "</em></code>").last().map(|answer| text(answer))
+}
+
+/// Extracts the examples from the HTML of a puzzle page.
+pub fn extract_examples(html: &str) -> Result<Vec<Example>, ExtractError> {
+    let mut examples: Vec<Example> = Vec::new();
+    for (part, article) in between(html, "<article", "</article>").into_iter().enumerate() {
+        let answer = answer(article);
+        match example_input(article) {
+            Some(input) if part == 0 => {
+                let answers = Answers { part1: answer, ..Answers::default() };
+                examples.push(Example { input, answers });
+            }
+            Some(input) => {
+                let answers = Answers { part2: answer, ..Answers::default() };
+                examples.push(Example { input, answers });
+            }
+            None => match (answer, examples.first_mut()) {
+                (None, _) => {}
+                (Some(answer), Some(example)) if part == 1 => example.answers.part2 = Some(answer),
+                (Some(answer), _) => {
+                    return Err(ExtractError::AnswerWithoutExample { part: part + 1, answer });
+                }
+            },
+        }
+    }
+
+    Ok(examples)
+}
+
+/// Writes the examples into the given directory as `sample.txt`, `sample2.txt`, etc, each with
+/// its `.answers` file, and returns the paths of the inputs. Fails without writing anything if any
+/// of the files already exist.
+pub fn write_examples(dir: &Path, examples: &[Example]) -> io::Result<Vec<PathBuf>> {
+    let inputs: Vec<_> = (1..=examples.len())
+        .map(|i| match i {
+            1 => dir.join("sample.txt"),
+            _ => dir.join(format!("sample{i}.txt")),
+        })
+        .collect();
+
+    for path in inputs.iter().flat_map(|input| [input.clone(), input.with_extension("answers")]) {
+        if path.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", path.display()),
+            ));
+        }
+    }
+
+    fs::create_dir_all(dir)?;
+    for (input, example) in inputs.iter().zip(examples) {
+        fs::write(input, &example.input)?;
+        fs::write(input.with_extension("answers"), example.answers.to_string())?;
+    }
+
+    Ok(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 99: Test ---</h2><p>For example:</p>
+<pre><code>1 &lt; 2
+3 &amp; <em>4</em>
+</code></pre>
+<p>The second line is <code>3 &amp; 4</code>.</p>
+<pre><code>1 &lt; 2
+</code></pre>
+<p>So the answer is <code><em>10</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>12345</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Now the answer is <em><code>24</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn shared_example() {
+        let examples = extract_examples(PAGE).unwrap();
+        assert_eq!(1, examples.len());
+        assert_eq!("1 < 2\n3 & 4\n", examples[0].input);
+        assert_eq!(Some("10".into()), examples[0].answers.part1);
+        assert_eq!(Some("24".into()), examples[0].answers.part2);
+    }
+
+    #[test]
+    fn separate_examples() {
+        let page = PAGE.replace("<p>Now", "<pre><code>5\n6\n</code></pre><p>Now");
+        let examples = extract_examples(&page).unwrap();
+        assert_eq!(2, examples.len());
+        assert_eq!(None, examples[0].answers.part2);
+        assert_eq!("5\n6\n", examples[1].input);
+        assert_eq!(Answers { part2: Some("24".into()), ..Answers::default() }, examples[1].answers);
+
+        let part1_only = &PAGE[..PAGE.find("<p>Your").unwrap()];
+        assert_eq!(None, extract_examples(part1_only).unwrap()[0].answers.part2);
+    }
+
+    #[test]
+    fn answer_without_example() {
+        let page = PAGE.replacen("<pre><code>", "<p>", 1).replacen("</code></pre>", "</p>", 1);
+        let page = page.replacen("<pre><code>", "<p>", 1).replacen("</code></pre>", "</p>", 1);
+        let err = ExtractError::AnswerWithoutExample { part: 1, answer: "10".into() };
+        assert_eq!(Err(err), extract_examples(&page));
+
+        let page = page.replace("<p>Now", "<pre><code>5\n</code></pre><p>Now");
+        let err = ExtractError::AnswerWithoutExample { part: 1, answer: "10".into() };
+        assert_eq!(Err(err), extract_examples(&page));
+
+        let page = page.replace("<code><em>10</em></code>", "10");
+        assert_eq!(1, extract_examples(&page).unwrap().len());
+    }
+
+    #[test]
+    fn write_without_overwriting() {
+        let dir = std::env::temp_dir().join(format!("aoc-puzzle-page-{}", std::process::id()));
+        let examples = extract_examples(PAGE).unwrap();
+
+        let inputs = write_examples(&dir, &examples).unwrap();
+        assert_eq!(vec![dir.join("sample.txt")], inputs);
+        assert_eq!("1 < 2\n3 & 4\n", fs::read_to_string(&inputs[0]).unwrap());
+        let answers = fs::read_to_string(dir.join("sample.answers")).unwrap();
+        assert_eq!(examples[0].answers, Answers::parse(&answers).unwrap());
+
+        let err = write_examples(&dir, &examples).unwrap_err();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(io::ErrorKind::AlreadyExists, err.kind());
+    }
+}
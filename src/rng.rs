@@ -0,0 +1,114 @@
+//! Small deterministic pseudorandom number generator (SplitMix64)
+//!
+//! This is not suitable for anything security-sensitive. It exists so that generated inputs and
+//! randomized tests are reproducible from a seed without pulling in a dependency.
+
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n`, using rejection sampling to avoid modulo bias.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Cannot sample from an empty range");
+
+        let zone = u64::MAX - (u64::MAX - n + 1) % n;
+        loop {
+            let value = self.next_u64();
+            if value <= zone {
+                return value % n;
+            }
+        }
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Cannot sample from an empty range");
+
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.below(len),
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// Returns true with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        // Reference values for SplitMix64 seeded with 0
+        let mut rng = Rng::new(0);
+        assert_eq!(0xe220_a839_7b1d_cdaf, rng.next_u64());
+        assert_eq!(0x6e78_9e6a_a1b9_65f4, rng.next_u64());
+
+        let a: Vec<_> = (0..10)
+            .map({
+                let mut rng = Rng::new(42);
+                move |_| rng.range(1..=6)
+            })
+            .collect();
+        let b: Vec<_> = (0..10)
+            .map({
+                let mut rng = Rng::new(42);
+                move |_| rng.range(1..=6)
+            })
+            .collect();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(7);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let value = rng.range(10..=15);
+            assert!((10..=15).contains(&value));
+            seen[(value - 10) as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        assert_eq!(5, rng.range(5..=5));
+        rng.range(0..=u64::MAX);
+
+        let mut items: Vec<_> = (0..20).collect();
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!((0..20).collect::<Vec<_>>(), items);
+    }
+}
@@ -0,0 +1,239 @@
+//! Scaffolding for a new day
+//!
+//! A new day takes a solver module (`src/day<N>.rs`), a thin executable (`src/bin/day<N>.rs`),
+//! an example in `examples/day<N>/`, and an entry in `src/lib.rs` for both the module declaration
+//! and [`SOLUTIONS`](crate::SOLUTIONS). The solver starts out solving nothing useful, so that the
+//! tree builds and the example test can be filled in first.
+
+use crate::examples::Answers;
+use crate::puzzle_page::{self, Example, ExtractError};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(PathBuf),
+    AlreadyRegistered(usize),
+    MissingRegistry,
+    Extract(ExtractError),
+    Io(io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            Self::AlreadyRegistered(day) => write!(f, "Day {day} is already in src/lib.rs"),
+            Self::MissingRegistry => write!(f, "src/lib.rs has no SOLUTIONS list"),
+            Self::Extract(err) => write!(f, "{err}"),
+            Self::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+impl From<ExtractError> for ScaffoldError {
+    fn from(err: ExtractError) -> Self {
+        Self::Extract(err)
+    }
+}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+const REGISTRY_START: &str = "pub const SOLUTIONS: &[Solution] = &[";
+
+fn solver_module(day: usize, title: &str) -> String {
+    format!(
+        r#"//! Day {day}: {title}
+//!
+//! <https://adventofcode.com/2025/day/{day}>
+
+use crate::Solution;
+
+pub fn parse(input: &str) -> Vec<&str> {{
+    input.lines().collect()
+}}
+
+pub fn part1(lines: &[&str]) -> usize {{
+    lines.len()
+}}
+
+pub fn part2(lines: &[&str]) -> usize {{
+    lines.len()
+}}
+
+pub const SOLUTION: Solution = Solution {{
+    day: {day},
+    params: &[],
+    part1: |input, _| part1(&parse(input)).to_string(),
+    part2: |input, _| part2(&parse(input)).to_string(),
+}};
+
+#[cfg(test)]
+mod tests {{
+    crate::aoc_test!(day{day});
+}}
+"#
+    )
+}
+
+fn executable(day: usize, title: &str) -> String {
+    format!(
+        r#"//! Day {day}: {title}
+
+use advent_of_code_2025::day{day};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {{
+    advent_of_code_2025::run(
+        |input| day{day}::part1(&day{day}::parse(input)),
+        |input| day{day}::part2(&day{day}::parse(input)),
+    )
+}}
+"#
+    )
+}
+
+// An example without answers, ignored until it's filled in
+fn example_stub() -> Example {
+    let ignore = "Not filled in yet; add the input and e.g. `part1 = 42`, then remove this line";
+    Example {
+        input: String::new(),
+        answers: Answers { ignore: Some(ignore.into()), ..Answers::default() },
+    }
+}
+
+// Inserts the module declaration in the order rustfmt sorts them, and the solution in order of
+// day
+fn register(lib: &str, day: usize) -> Result<String, ScaffoldError> {
+    let module = format!("day{day}");
+
+    let mut lines: Vec<String> = lib.lines().map(String::from).collect();
+    if lines.iter().any(|line| *line == format!("pub mod {module};")) {
+        return Err(ScaffoldError::AlreadyRegistered(day));
+    }
+
+    let declarations: Vec<_> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod ") && lines[i].ends_with(';'))
+        .collect();
+    let position = declarations
+        .iter()
+        .find(|&&i| lines[i]["pub mod ".len()..lines[i].len() - 1] > *module)
+        .map_or_else(|| declarations.last().map_or(0, |&i| i + 1), |&i| i);
+    lines.insert(position, format!("pub mod {module};"));
+
+    let start = lines
+        .iter()
+        .position(|line| *line == REGISTRY_START)
+        .ok_or(ScaffoldError::MissingRegistry)?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .ok_or(ScaffoldError::MissingRegistry)?;
+    let entry_day = |line: &str| {
+        line.trim().strip_prefix("day")?.strip_suffix("::SOLUTION,")?.parse::<usize>().ok()
+    };
+    let position = (start + 1..end)
+        .find(|&i| entry_day(&lines[i]).is_some_and(|entry_day| entry_day > day))
+        .unwrap_or(end);
+    lines.insert(position, format!("    {module}::SOLUTION,"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates the files for a new day under the given crate root and registers the day, and returns
+/// the paths of the new files. The example comes from a saved puzzle page if one is given, and is
+/// otherwise a stub. Fails without changing anything if any of the day's files already exist.
+pub fn new_day(
+    root: &Path,
+    day: usize,
+    title: &str,
+    page: Option<&str>,
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let solver_path = root.join("src").join(format!("day{day}.rs"));
+    let executable_path = root.join("src").join("bin").join(format!("day{day}.rs"));
+    let examples_dir = root.join("examples").join(format!("day{day}"));
+    for path in [&solver_path, &executable_path, &examples_dir] {
+        if path.exists() {
+            return Err(ScaffoldError::AlreadyExists(path.clone()));
+        }
+    }
+
+    let lib_path = root.join("src").join("lib.rs");
+    let lib = register(&fs::read_to_string(&lib_path)?, day)?;
+
+    let examples = match page.map(puzzle_page::extract_examples).transpose()? {
+        Some(examples) if !examples.is_empty() => examples,
+        _ => vec![example_stub()],
+    };
+
+    fs::write(&solver_path, solver_module(day, title))?;
+    fs::write(&executable_path, executable(day, title))?;
+    let mut paths = vec![solver_path, executable_path];
+    paths.extend(puzzle_page::write_examples(&examples_dir, &examples)?);
+    fs::write(&lib_path, lib)?;
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "pub mod automaton;
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod graph;
+
+pub const SOLUTIONS: &[Solution] = &[
+    day1::SOLUTION,
+    day2::SOLUTION,
+    day10::SOLUTION,
+];
+";
+
+    #[test]
+    fn registers_in_order() {
+        let lib = register(LIB, 3).unwrap();
+        assert!(lib.contains("pub mod day2;\npub mod day3;\npub mod graph;\n"), "{lib}");
+        assert!(lib.contains("day2::SOLUTION,\n    day3::SOLUTION,\n    day10::SOLUTION,\n"));
+
+        let lib = register(LIB, 11).unwrap();
+        assert!(lib.contains("pub mod day10;\npub mod day11;\npub mod day2;\n"), "{lib}");
+        assert!(lib.contains("day10::SOLUTION,\n    day11::SOLUTION,\n];\n"), "{lib}");
+
+        assert!(matches!(register(LIB, 10), Err(ScaffoldError::AlreadyRegistered(10))));
+        assert!(matches!(register("pub mod day1;\n", 2), Err(ScaffoldError::MissingRegistry)));
+    }
+
+    #[test]
+    fn refuses_to_overwrite() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src").join("bin")).unwrap();
+        fs::write(root.join("src").join("lib.rs"), LIB).unwrap();
+
+        let paths = new_day(&root, 3, "Test", None).unwrap();
+        assert_eq!(3, paths.len());
+        let solver = fs::read_to_string(&paths[0]).unwrap();
+        assert!(solver.starts_with("//! Day 3: Test\n"), "{solver}");
+        let answers = fs::read_to_string(paths[2].with_extension("answers")).unwrap();
+        assert!(Answers::parse(&answers).unwrap().ignore.is_some());
+        let lib = fs::read_to_string(root.join("src").join("lib.rs")).unwrap();
+
+        let result = new_day(&root, 3, "Test", None);
+        let unchanged = fs::read_to_string(root.join("src").join("lib.rs")).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert!(matches!(result, Err(ScaffoldError::AlreadyExists(path)) if path == paths[0]));
+        assert_eq!(lib, unchanged);
+    }
+}
@@ -0,0 +1,79 @@
+//! Disjoint-set forest with union by size and path halving
+
+use std::{cmp, mem};
+
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    max_size: usize,
+    components: usize,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            max_size: cmp::min(len, 1),
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    pub fn find(&mut self, mut i: usize) -> usize {
+        while self.parents[i] != i {
+            let t = self.parents[i];
+            self.parents[i] = self.parents[self.parents[i]];
+            i = t;
+        }
+
+        i
+    }
+
+    /// Merges the sets containing i and j. Returns false if they were already in the same set.
+    pub fn union(&mut self, mut i: usize, mut j: usize) -> bool {
+        i = self.find(i);
+        j = self.find(j);
+
+        if i == j {
+            return false;
+        }
+
+        if self.sizes[i] < self.sizes[j] {
+            mem::swap(&mut i, &mut j);
+        }
+
+        self.parents[j] = i;
+        self.sizes[i] += self.sizes[j];
+        self.max_size = cmp::max(self.max_size, self.sizes[i]);
+        self.components -= 1;
+
+        true
+    }
+
+    pub fn size_of(&mut self, i: usize) -> usize {
+        let root = self.find(i);
+        self.sizes[root]
+    }
+
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Returns the size of every set, in order of each set's root index.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len()).filter(|&i| self.parents[i] == i).map(|i| self.sizes[i]).collect()
+    }
+}